@@ -20,21 +20,40 @@ fn std_vec_new<T, F: Fn(usize) -> T>(n: usize, value: F) {
     for i in 0..n {
         vec.push(value(i))
     }
+    black_box(vec);
 }
 fn std_vec_with_capacity<T, F: Fn(usize) -> T>(n: usize, value: F) {
     let mut vec = Vec::with_capacity(n);
     for i in 0..n {
         vec.push(value(i))
     }
+    black_box(vec);
 }
 fn fixed_vec<T, F: Fn(usize) -> T>(n: usize, value: F) {
     let mut vec = FixedVec::new(n);
     for i in 0..n {
         vec.push(value(i))
     }
+    black_box(vec);
+}
+fn fixed_vec_from_fn<T, F: Fn(usize) -> T>(n: usize, value: F) {
+    let vec = FixedVec::from_fn(n, value);
+    black_box(vec);
+}
+fn fixed_vec_repeat_with<T, F: Fn(usize) -> T>(n: usize, value: F) {
+    let mut i = 0;
+    let vec = FixedVec::repeat_with(n, || {
+        i += 1;
+        value(i - 1)
+    });
+    black_box(vec);
+}
+fn fixed_vec_filled<T: Clone, F: Fn(usize) -> T>(n: usize, value: F) {
+    let vec = FixedVec::filled(n, value(n));
+    black_box(vec);
 }
 
-fn test_for_type<T>(
+fn test_for_type<T: Clone>(
     group: &mut BenchmarkGroup<'_, WallTime>,
     num_u64s: usize,
     treatments: &[usize],
@@ -56,6 +75,24 @@ fn test_for_type<T>(
         group.bench_with_input(BenchmarkId::new("fixed_vec", &treatment), n, |b, _| {
             b.iter(|| fixed_vec(black_box(*n), value))
         });
+
+        group.bench_with_input(
+            BenchmarkId::new("fixed_vec_from_fn", &treatment),
+            n,
+            |b, _| b.iter(|| fixed_vec_from_fn(black_box(*n), value)),
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("fixed_vec_repeat_with", &treatment),
+            n,
+            |b, _| b.iter(|| fixed_vec_repeat_with(black_box(*n), value)),
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("fixed_vec_filled", &treatment),
+            n,
+            |b, _| b.iter(|| fixed_vec_filled(black_box(*n), value)),
+        );
     }
 }
 
@@ -1,18 +1,19 @@
 use crate::FixedVec;
+use allocator_api2::alloc::Allocator;
 use core::ops::Deref;
 
-impl<T> AsRef<[T]> for FixedVec<T> {
+impl<T, A: Allocator> AsRef<[T]> for FixedVec<T, A> {
     fn as_ref(&self) -> &[T] {
         &self.data
     }
 }
-impl<T> AsMut<[T]> for FixedVec<T> {
+impl<T, A: Allocator> AsMut<[T]> for FixedVec<T, A> {
     fn as_mut(&mut self) -> &mut [T] {
         &mut self.data
     }
 }
 
-impl<T> Deref for FixedVec<T> {
+impl<T, A: Allocator> Deref for FixedVec<T, A> {
     type Target = [T];
 
     fn deref(&self) -> &Self::Target {
@@ -0,0 +1,36 @@
+use crate::FixedVec;
+use allocator_api2::alloc::Allocator;
+use core::borrow::{Borrow, BorrowMut};
+
+/// Borrows the vector as a slice; since `Eq`, `Ord` and `Hash` of the fixed vector follow slice semantics,
+/// collections keyed by fixed vectors can be queried by slices.
+impl<T, A: Allocator> Borrow<[T]> for FixedVec<T, A> {
+    fn borrow(&self) -> &[T] {
+        &self.data
+    }
+}
+
+impl<T, A: Allocator> BorrowMut<[T]> for FixedVec<T, A> {
+    fn borrow_mut(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use core::borrow::{Borrow, BorrowMut};
+
+    #[test]
+    fn borrow() {
+        let mut vec = FixedVec::new(4);
+        vec.extend_from_slice(&[0, 1, 2]);
+
+        let slice: &[i32] = vec.borrow();
+        assert_eq!(slice, &[0, 1, 2]);
+
+        let slice: &mut [i32] = vec.borrow_mut();
+        slice[0] = 42;
+        assert_eq!(vec, &[42, 1, 2]);
+    }
+}
@@ -1,13 +1,14 @@
 use crate::FixedVec;
-use alloc::vec::Vec;
+use allocator_api2::{alloc::Allocator, vec::Vec as AllocVec};
 
-impl<T> Clone for FixedVec<T>
+impl<T, A> Clone for FixedVec<T, A>
 where
     T: Clone,
+    A: Allocator + Clone,
 {
     fn clone(&self) -> Self {
-        let mut data = Vec::with_capacity(self.data.capacity());
+        let mut data = AllocVec::with_capacity_in(self.capacity, self.allocator().clone());
         data.extend_from_slice(&self.data);
-        Self { data }
+        Self::from_parts(data, self.capacity)
     }
 }
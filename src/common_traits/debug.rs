@@ -1,9 +1,11 @@
 use crate::FixedVec;
+use allocator_api2::alloc::Allocator;
 use core::fmt::Debug;
 
-impl<T> Debug for FixedVec<T>
+impl<T, A> Debug for FixedVec<T, A>
 where
     T: Debug,
+    A: Allocator,
 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("FixedVec")
@@ -0,0 +1,57 @@
+use crate::FixedVec;
+use allocator_api2::{alloc::Allocator, vec::Vec as AllocVec};
+
+/// Creates an empty vector with zero fixed capacity, without allocating.
+///
+/// Since a fixed vector can never grow, the default vector cannot hold any elements; pushing to it panics.
+/// It is provided as a cheap placeholder value, such as the value left behind by `core::mem::take`,
+/// and it is identical to the `PseudoDefault` value required by the `PinnedVec` trait.
+/// [`FixedVec::new`] must be used to create a vector that can hold elements.
+impl<T, A: Allocator + Default> Default for FixedVec<T, A> {
+    fn default() -> Self {
+        Self::from_parts(AllocVec::new_in(A::default()), 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use orx_pseudo_default::PseudoDefault;
+
+    #[test]
+    fn default() {
+        let vec = FixedVec::<char>::default();
+        assert!(vec.is_empty());
+        assert!(vec.is_full());
+        assert_eq!(vec.capacity(), 0);
+
+        let vec = FixedVec::<()>::default();
+        assert_eq!(vec.capacity(), 0);
+
+        assert_eq!(FixedVec::<char>::default(), FixedVec::<char>::empty());
+        assert_eq!(FixedVec::<char>::pseudo_default().capacity(), 0);
+    }
+
+    #[test]
+    fn take() {
+        let mut vec = FixedVec::new(4);
+        vec.extend_from_slice(&[1, 2, 3]);
+
+        let taken = core::mem::take(&mut vec);
+        assert_eq!(taken, &[1, 2, 3]);
+        assert_eq!(taken.capacity(), 4);
+        assert_eq!(vec.capacity(), 0);
+    }
+
+    #[test]
+    fn empty_in_const() {
+        const EMPTY: FixedVec<u64> = FixedVec::empty();
+        static STATIC_EMPTY: FixedVec<u64> = FixedVec::empty();
+
+        assert_eq!(EMPTY.capacity(), 0);
+        assert!(STATIC_EMPTY.is_empty());
+
+        let mut vec = EMPTY;
+        assert_eq!(vec.try_push(1).map_err(|e| e.into_rejected()), Err(1));
+    }
+}
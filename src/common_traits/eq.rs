@@ -1,15 +1,24 @@
 use crate::FixedVec;
+use allocator_api2::alloc::Allocator;
 
-impl<T, U> PartialEq<U> for FixedVec<T>
+/// Equality of fixed vectors follows slice semantics: two vectors are equal if they have equal elements
+/// in the same order, regardless of their fixed capacities.
+///
+/// Capacity is deliberately excluded so that equality is consistent with the comparison to slices and
+/// standard vectors, as well as with the `Hash`, `Ord` and `Borrow<[T]>` implementations.
+impl<T, U, A> PartialEq<U> for FixedVec<T, A>
 where
     U: AsRef<[T]>,
     T: PartialEq,
+    A: Allocator,
 {
     fn eq(&self, other: &U) -> bool {
         self.data.as_slice() == other.as_ref()
     }
 }
 
+impl<T: Eq, A: Allocator> Eq for FixedVec<T, A> {}
+
 #[cfg(test)]
 mod tests {
     use crate::prelude::*;
@@ -25,4 +34,23 @@ mod tests {
         let slice = &(0..vec.capacity()).collect::<Vec<_>>();
         assert_eq!(vec, slice);
     }
+
+    #[test]
+    fn eq_ignores_capacity() {
+        let mut a = FixedVec::new(3);
+        a.extend_from_slice(&['a', 'b']);
+        let mut b = FixedVec::new(10);
+        b.extend_from_slice(&['a', 'b']);
+
+        assert_ne!(a.capacity(), b.capacity());
+        assert_eq!(a, b);
+        assert_eq!(b, a);
+
+        b.push('c');
+        assert_ne!(a, b);
+
+        let empty1 = FixedVec::<char>::new(0);
+        let empty2 = FixedVec::<char>::new(5);
+        assert_eq!(empty1, empty2);
+    }
 }
@@ -0,0 +1,98 @@
+use crate::FixedVec;
+use allocator_api2::alloc::Allocator;
+
+impl<T, A: Allocator> Extend<T> for FixedVec<T, A> {
+    /// Extends the vector with the elements of the iterator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the iterator yields more elements than the available room of the vector.
+    /// [`FixedVec::try_extend`] or [`FixedVec::extend_bounded`] can be used to extend without panicking.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        // size hint is not trusted since exceeding the capacity would reallocate the elements
+        for x in iter {
+            self.push_or_panic(x);
+        }
+    }
+}
+
+impl<'a, T, A> Extend<&'a T> for FixedVec<T, A>
+where
+    T: Copy + 'a,
+    A: Allocator,
+{
+    /// Extends the vector with copies of the elements of the iterator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the iterator yields more elements than the available room of the vector.
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use alloc::string::{String, ToString};
+    use alloc::vec::Vec;
+
+    #[test]
+    fn extend() {
+        let mut vec = FixedVec::new(10);
+        vec.extend((0..4).map(|x| x.to_string()));
+        vec.extend(Vec::<String>::new());
+        vec.extend((4..).filter(|x| x % 2 == 0).take(6).map(|x| x.to_string()));
+        assert_eq!(vec.len(), 10);
+        assert!(vec.is_full());
+        assert_eq!(&vec[3], "3");
+        assert_eq!(&vec[4], "4");
+        assert_eq!(&vec[9], "14");
+    }
+
+    #[test]
+    fn extend_does_not_reallocate() {
+        let mut vec = FixedVec::new(10);
+        vec.push(0);
+        let addr0 = &vec[0] as *const i32;
+
+        vec.extend(1..10);
+        assert_eq!(vec, (0..10).collect::<Vec<_>>());
+        assert_eq!(vec.capacity(), 10);
+        assert_eq!(addr0, &vec[0] as *const i32);
+    }
+
+    #[test]
+    fn extend_ref() {
+        let mut vec = FixedVec::new(5);
+        let source = [0, 1, 2];
+        vec.extend(&source);
+        vec.extend(source[..2].iter());
+        assert_eq!(vec, &[0, 1, 2, 0, 1]);
+    }
+
+    #[test]
+    fn extend_generic_sink() {
+        fn fill<E: Extend<usize>>(sink: &mut E) {
+            sink.extend((0..100).filter(|x| x % 10 == 0));
+        }
+
+        let mut vec = FixedVec::new(10);
+        fill(&mut vec);
+        assert_eq!(vec, (0..10).map(|x| x * 10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    #[should_panic]
+    fn extend_exceeding_room() {
+        let mut vec = FixedVec::new(5);
+        vec.extend(0..6);
+    }
+
+    #[test]
+    #[should_panic]
+    fn extend_exceeding_room_unknown_len() {
+        let mut vec = FixedVec::new(5);
+        vec.extend((0..100).filter(|x| x % 2 == 0));
+    }
+}
@@ -2,6 +2,13 @@ use crate::FixedVec;
 use alloc::vec::Vec;
 
 impl<T> FromIterator<T> for FixedVec<T> {
+    /// Collects the elements of the iterator into a fixed vector.
+    ///
+    /// Fixed capacity of the created vector is at least the number of collected elements;
+    /// however, it might be greater depending on the size hint of the iterator.
+    ///
+    /// [`FixedVec::collect_exact`] or [`FixedVec::from_iter_with_capacity`] can be used
+    /// whenever the capacity must be deterministic.
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
         let vec: Vec<_> = iter.into_iter().collect();
         vec.into()
@@ -0,0 +1,58 @@
+use crate::FixedVec;
+use allocator_api2::alloc::Allocator;
+use core::hash::{Hash, Hasher};
+
+/// Hashes the elements of the vector exactly as the slice `&[T]` does; fixed capacity is not hashed.
+///
+/// This is consistent with the equality which ignores the capacity, and it allows to look up vectors
+/// in hash based collections by slices through the `Borrow<[T]>` implementation.
+impl<T: Hash, A: Allocator> Hash for FixedVec<T, A> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.data.as_slice().hash(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use core::hash::{BuildHasher, Hash};
+    use std::collections::hash_map::RandomState;
+    use std::collections::{HashMap, HashSet};
+
+    fn hash_of<T: Hash + ?Sized>(state: &RandomState, value: &T) -> u64 {
+        state.hash_one(value)
+    }
+
+    #[test]
+    fn hash_equals_slice_hash() {
+        let state = RandomState::new();
+
+        let mut vec = FixedVec::new(8);
+        vec.extend_from_slice(&[1, 2, 3]);
+        assert_eq!(hash_of(&state, &vec), hash_of(&state, &[1, 2, 3][..]));
+
+        let mut other = FixedVec::new(3);
+        other.extend_from_slice(&[1, 2, 3]);
+        assert_eq!(hash_of(&state, &vec), hash_of(&state, &other));
+    }
+
+    #[test]
+    fn hash_map_key() {
+        let mut map = HashMap::new();
+
+        let mut key = FixedVec::new(4);
+        key.extend_from_slice(&["a", "b"]);
+        map.insert(key, 42);
+
+        assert_eq!(map.get(&["a", "b"][..]), Some(&42));
+        assert_eq!(map.get(&["a"][..]), None);
+
+        let mut key = FixedVec::new(2);
+        key.extend_from_slice(&["a", "b"]);
+        assert_eq!(map.insert(key, 7), Some(42));
+        assert_eq!(map.len(), 1);
+
+        let set: HashSet<_> = map.into_keys().collect();
+        assert!(set.contains(&["a", "b"][..]));
+    }
+}
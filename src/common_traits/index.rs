@@ -1,10 +1,12 @@
 use crate::FixedVec;
+use allocator_api2::alloc::Allocator;
 use core::ops::{Index, IndexMut};
 use core::slice::SliceIndex;
 
-impl<T, I> Index<I> for FixedVec<T>
+impl<T, I, A> Index<I> for FixedVec<T, A>
 where
     I: SliceIndex<[T]>,
+    A: Allocator,
 {
     type Output = I::Output;
 
@@ -12,9 +14,10 @@ where
         &self.data[index]
     }
 }
-impl<T, I> IndexMut<I> for FixedVec<T>
+impl<T, I, A> IndexMut<I> for FixedVec<T, A>
 where
     I: SliceIndex<[T]>,
+    A: Allocator,
 {
     fn index_mut(&mut self, index: I) -> &mut Self::Output {
         &mut self.data[index]
@@ -1,16 +1,77 @@
 use crate::FixedVec;
-use alloc::vec::IntoIter;
+use allocator_api2::{
+    alloc::{Allocator, Global},
+    vec::IntoIter,
+};
+use core::fmt::Debug;
+use core::iter::FusedIterator;
 
-impl<T> IntoIterator for FixedVec<T> {
+/// An iterator that moves out of a [`FixedVec`].
+pub struct FixedVecIntoIter<T, A: Allocator = Global> {
+    iter: IntoIter<T, A>,
+}
+
+impl<T, A: Allocator> FixedVecIntoIter<T, A> {
+    /// Returns the remaining elements of the iterator as a slice.
+    pub fn as_slice(&self) -> &[T] {
+        self.iter.as_slice()
+    }
+
+    /// Returns the remaining elements of the iterator as a mutable slice.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self.iter.as_mut_slice()
+    }
+}
+
+impl<T: Debug, A: Allocator> Debug for FixedVecIntoIter<T, A> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("FixedVecIntoIter")
+            .field(&self.as_slice())
+            .finish()
+    }
+}
+
+impl<T, A: Allocator> Iterator for FixedVecIntoIter<T, A> {
+    type Item = T;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T, A: Allocator> DoubleEndedIterator for FixedVecIntoIter<T, A> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back()
+    }
+}
+
+impl<T, A: Allocator> ExactSizeIterator for FixedVecIntoIter<T, A> {
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<T, A: Allocator> FusedIterator for FixedVecIntoIter<T, A> {}
+
+impl<T, A: Allocator> IntoIterator for FixedVec<T, A> {
     type Item = T;
-    type IntoIter = IntoIter<T>;
+    type IntoIter = FixedVecIntoIter<T, A>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.data.into_iter()
+        FixedVecIntoIter {
+            iter: self.data.into_iter(),
+        }
     }
 }
 
-impl<'a, T> IntoIterator for &'a FixedVec<T> {
+impl<'a, T, A: Allocator> IntoIterator for &'a FixedVec<T, A> {
     type Item = &'a T;
     type IntoIter = core::slice::Iter<'a, T>;
 
@@ -19,7 +80,7 @@ impl<'a, T> IntoIterator for &'a FixedVec<T> {
     }
 }
 
-impl<'a, T> IntoIterator for &'a mut FixedVec<T> {
+impl<'a, T, A: Allocator> IntoIterator for &'a mut FixedVec<T, A> {
     type Item = &'a mut T;
     type IntoIter = core::slice::IterMut<'a, T>;
 
@@ -43,4 +104,18 @@ mod tests {
         let vec_from_iter: Vec<_> = vec.into_iter().map(|x| x + 1).collect();
         assert_eq!(vec_from_iter, &[1, 2, 3, 4]);
     }
+
+    #[test]
+    fn into_iter_from_both_ends() {
+        let vec: FixedVec<_> = ['a', 'b', 'c', 'd'].into_iter().collect();
+
+        let mut iter = vec.into_iter();
+        assert_eq!(iter.len(), 4);
+        assert_eq!(iter.next(), Some('a'));
+        assert_eq!(iter.next_back(), Some('d'));
+        assert_eq!(iter.as_slice(), &['b', 'c']);
+
+        iter.as_mut_slice()[0] = 'x';
+        assert_eq!(iter.collect::<Vec<_>>(), &['x', 'c']);
+    }
 }
@@ -2,10 +2,19 @@
 mod tests;
 
 mod as_ref;
+mod borrow;
 mod clone;
 mod debug;
+mod default;
 mod eq;
+mod extend;
 mod from_iter;
+mod hash;
 mod index;
 mod into_iter;
+mod ord;
 pub mod ptr_iter;
+#[cfg(feature = "serde")]
+mod serialization;
+
+pub use into_iter::FixedVecIntoIter;
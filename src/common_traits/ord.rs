@@ -0,0 +1,78 @@
+use crate::FixedVec;
+use allocator_api2::alloc::Allocator;
+use core::cmp::Ordering;
+
+/// Compares the elements of the vectors lexicographically as slices; fixed capacity is not compared.
+impl<T: PartialOrd, A: Allocator> PartialOrd for FixedVec<T, A> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.data.as_slice().partial_cmp(other.data.as_slice())
+    }
+}
+
+/// Compares the elements of the vectors lexicographically as slices; fixed capacity is not compared.
+impl<T: Ord, A: Allocator> Ord for FixedVec<T, A> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.data.as_slice().cmp(other.data.as_slice())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use alloc::collections::BTreeSet;
+    use alloc::vec::Vec;
+    use core::cmp::Ordering;
+
+    fn fixed_vec(capacity: usize, values: &[i32]) -> FixedVec<i32> {
+        let mut vec = FixedVec::new(capacity);
+        vec.extend_from_slice(values);
+        vec
+    }
+
+    #[test]
+    fn cmp_lexicographic() {
+        assert_eq!(
+            fixed_vec(4, &[1, 2]).cmp(&fixed_vec(4, &[1, 3])),
+            Ordering::Less
+        );
+        assert_eq!(
+            fixed_vec(4, &[1, 2]).cmp(&fixed_vec(4, &[1])),
+            Ordering::Greater
+        );
+        assert_eq!(fixed_vec(4, &[]).cmp(&fixed_vec(4, &[0])), Ordering::Less);
+
+        // capacity is not compared
+        assert_eq!(
+            fixed_vec(2, &[1, 2]).cmp(&fixed_vec(9, &[1, 2])),
+            Ordering::Equal
+        );
+        assert_eq!(fixed_vec(9, &[0]).cmp(&fixed_vec(1, &[1])), Ordering::Less);
+    }
+
+    #[test]
+    fn partial_cmp() {
+        let mut a = FixedVec::new(2);
+        a.push(f32::NAN);
+        let mut b = FixedVec::new(2);
+        b.push(1.0);
+
+        assert_eq!(a.partial_cmp(&b), None);
+        assert!(b < FixedVec::from_vec_exact(alloc::vec![2.0]));
+    }
+
+    #[test]
+    fn b_tree_set() {
+        let mut set = BTreeSet::new();
+        set.insert(fixed_vec(5, &[3]));
+        set.insert(fixed_vec(2, &[1, 2]));
+        set.insert(fixed_vec(8, &[1, 2]));
+        set.insert(fixed_vec(1, &[2]));
+
+        assert_eq!(set.len(), 3);
+        let sorted: Vec<_> = set.iter().map(|x| x.as_slice()).collect();
+        assert_eq!(sorted, [&[1, 2][..], &[2], &[3]]);
+
+        assert!(set.contains(&[2][..]));
+        assert!(!set.contains(&[4][..]));
+    }
+}
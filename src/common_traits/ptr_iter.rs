@@ -1,11 +1,13 @@
+use core::ptr::NonNull;
+
 pub struct FixedVecPtrIter<T> {
-    ptr: *mut T,
+    ptr: NonNull<T>,
     len: usize,
     current: usize,
 }
 
 impl<T> FixedVecPtrIter<T> {
-    pub(crate) fn new(ptr: *mut T, len: usize) -> Self {
+    pub(crate) fn new(ptr: NonNull<T>, len: usize) -> Self {
         Self {
             ptr,
             len,
@@ -17,7 +19,7 @@ impl<T> FixedVecPtrIter<T> {
 impl<T> Default for FixedVecPtrIter<T> {
     fn default() -> Self {
         Self {
-            ptr: core::ptr::null_mut(),
+            ptr: NonNull::dangling(),
             len: 0,
             current: 0,
         }
@@ -33,7 +35,7 @@ impl<T> Iterator for FixedVecPtrIter<T> {
                 // SAFETY: current is within bounds of the vector
                 let ptr = unsafe { self.ptr.add(self.current) };
                 self.current += 1;
-                Some(ptr)
+                Some(ptr.as_ptr())
             }
             false => None,
         }
@@ -0,0 +1,364 @@
+use crate::FixedVec;
+use crate::helpers::vec::is_zst;
+use allocator_api2::{alloc::Allocator, vec::Vec as AllocVec};
+use core::{fmt, marker::PhantomData};
+use serde::de::{self, DeserializeSeed, Deserializer, MapAccess, SeqAccess, Visitor};
+use serde::ser::{SerializeStruct, Serializer};
+use serde::{Deserialize, Serialize};
+
+const NAME: &str = "FixedVec";
+const FIELDS: &[&str] = &["capacity", "data"];
+// upper bound on the memory reserved for the elements before they are read, since the capacity is untrusted
+const MAX_PREALLOCATED_BYTES: usize = 1024 * 1024;
+
+impl<T, A> Serialize for FixedVec<T, A>
+where
+    T: Serialize,
+    A: Allocator,
+{
+    /// Serializes the fixed vector as a struct with two fields:
+    /// * `capacity`: the fixed capacity of the vector, and
+    /// * `data`: the sequence of elements.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct(NAME, FIELDS.len())?;
+        state.serialize_field(FIELDS[0], &self.capacity)?;
+        state.serialize_field(FIELDS[1], self.as_slice())?;
+        state.end()
+    }
+}
+
+impl<'de, T, A> Deserialize<'de> for FixedVec<T, A>
+where
+    T: Deserialize<'de>,
+    A: Allocator + Default,
+{
+    /// Deserializes a fixed vector with the serialized fixed capacity,
+    /// so that a round trip results in a vector with identical `capacity()` and `room()`.
+    ///
+    /// Fails with an error, rather than panicking, if the length of the data exceeds the declared capacity.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_struct(NAME, FIELDS, FixedVecVisitor(PhantomData))
+    }
+}
+
+// visitors
+
+struct FixedVecVisitor<T, A>(PhantomData<(T, A)>);
+
+impl<'de, T, A> Visitor<'de> for FixedVecVisitor<T, A>
+where
+    T: Deserialize<'de>,
+    A: Allocator + Default,
+{
+    type Value = FixedVec<T, A>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("struct FixedVec")
+    }
+
+    fn visit_seq<V: SeqAccess<'de>>(self, mut seq: V) -> Result<Self::Value, V::Error> {
+        let capacity = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let data = seq
+            .next_element_seed(DataSeed::new(Some(capacity)))?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        into_fixed_vec(capacity, data)
+    }
+
+    fn visit_map<V: MapAccess<'de>>(self, mut map: V) -> Result<Self::Value, V::Error> {
+        let mut capacity = None;
+        let mut data = None;
+        while let Some(field) = map.next_key()? {
+            match field {
+                Field::Capacity => match capacity {
+                    Some(_) => return Err(de::Error::duplicate_field(FIELDS[0])),
+                    None => capacity = Some(map.next_value()?),
+                },
+                Field::Data => match data {
+                    Some(_) => return Err(de::Error::duplicate_field(FIELDS[1])),
+                    // capacity is used to allocate upfront whenever it precedes the data
+                    None => data = Some(map.next_value_seed(DataSeed::new(capacity))?),
+                },
+            }
+        }
+        let capacity = capacity.ok_or_else(|| de::Error::missing_field(FIELDS[0]))?;
+        let data = data.ok_or_else(|| de::Error::missing_field(FIELDS[1]))?;
+        into_fixed_vec(capacity, data)
+    }
+}
+
+struct DataSeed<T, A> {
+    capacity: Option<usize>,
+    phantom: PhantomData<(T, A)>,
+}
+
+impl<T, A> DataSeed<T, A> {
+    fn new(capacity: Option<usize>) -> Self {
+        Self {
+            capacity,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<'de, T, A> DeserializeSeed<'de> for DataSeed<T, A>
+where
+    T: Deserialize<'de>,
+    A: Allocator + Default,
+{
+    type Value = AllocVec<T, A>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de, T, A> Visitor<'de> for DataSeed<T, A>
+where
+    T: Deserialize<'de>,
+    A: Allocator + Default,
+{
+    type Value = AllocVec<T, A>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self.capacity {
+            Some(capacity) => write!(formatter, "a sequence of at most {} elements", capacity),
+            None => formatter.write_str("a sequence"),
+        }
+    }
+
+    fn visit_seq<V: SeqAccess<'de>>(self, mut seq: V) -> Result<Self::Value, V::Error> {
+        match self.capacity {
+            Some(capacity) => {
+                if let Some(len) = seq.size_hint().filter(|len| *len > capacity) {
+                    return Err(de::Error::invalid_length(len, &self));
+                }
+
+                let mut data =
+                    allocate::<T, A, V::Error>(preallocated_len::<T>(capacity, seq.size_hint()))?;
+                while let Some(value) = seq.next_element()? {
+                    if data.len() == capacity {
+                        return Err(de::Error::invalid_length(capacity + 1, &self));
+                    }
+                    if data.len() == data.capacity() {
+                        // grows geometrically while reading, but never beyond the capacity
+                        let additional = data.len().clamp(1, capacity - data.len());
+                        data.try_reserve_exact(additional)
+                            .map_err(de::Error::custom)?;
+                    }
+                    data.push(value);
+                }
+                // the entire capacity is allocated only after all elements are successfully read
+                data.try_reserve_exact(capacity - data.len())
+                    .map_err(de::Error::custom)?;
+                Ok(data)
+            }
+            None => {
+                let mut data = AllocVec::new_in(A::default());
+                while let Some(value) = seq.next_element()? {
+                    data.push(value);
+                }
+                Ok(data)
+            }
+        }
+    }
+}
+
+enum Field {
+    Capacity,
+    Data,
+}
+
+impl<'de> Deserialize<'de> for Field {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_identifier(FieldVisitor)
+    }
+}
+
+struct FieldVisitor;
+
+impl Visitor<'_> for FieldVisitor {
+    type Value = Field;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("`capacity` or `data`")
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+        match value {
+            0 => Ok(Field::Capacity),
+            1 => Ok(Field::Data),
+            _ => Err(de::Error::invalid_value(
+                de::Unexpected::Unsigned(value),
+                &self,
+            )),
+        }
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        match value {
+            "capacity" => Ok(Field::Capacity),
+            "data" => Ok(Field::Data),
+            _ => Err(de::Error::unknown_field(value, FIELDS)),
+        }
+    }
+}
+
+// helpers
+
+fn allocate<T, A, E>(capacity: usize) -> Result<AllocVec<T, A>, E>
+where
+    A: Allocator + Default,
+    E: de::Error,
+{
+    let mut data = AllocVec::new_in(A::default());
+    data.try_reserve_exact(capacity).map_err(E::custom)?;
+    Ok(data)
+}
+
+/// Number of elements to reserve before reading the sequence of a vector with the given untrusted `capacity`.
+fn preallocated_len<T>(capacity: usize, size_hint: Option<usize>) -> usize {
+    let max_len = MAX_PREALLOCATED_BYTES / core::mem::size_of::<T>().max(1);
+    capacity.min(size_hint.unwrap_or(0)).min(max_len)
+}
+
+fn into_fixed_vec<T, A, E>(capacity: usize, mut data: AllocVec<T, A>) -> Result<FixedVec<T, A>, E>
+where
+    A: Allocator + Default,
+    E: de::Error,
+{
+    match data.len() <= capacity {
+        true if is_zst::<T>() || data.capacity() == capacity => {
+            Ok(FixedVec::from_parts(data, capacity))
+        }
+        true => {
+            // data preceded the capacity and has been collected without knowing the capacity
+            let mut fixed = allocate(capacity)?;
+            fixed.append(&mut data);
+            Ok(FixedVec::from_parts(fixed, capacity))
+        }
+        false => Err(de::Error::invalid_length(
+            data.len(),
+            &"a sequence not longer than the fixed capacity",
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use alloc::string::{String, ToString};
+    use alloc::vec::Vec;
+
+    #[test]
+    fn round_trip() {
+        let mut vec = FixedVec::new(10);
+        for i in 0..4 {
+            vec.push(i.to_string());
+        }
+
+        let json = serde_json::to_string(&vec).expect("serializable");
+        assert_eq!(json, r#"{"capacity":10,"data":["0","1","2","3"]}"#);
+
+        let de: FixedVec<String> = serde_json::from_str(&json).expect("deserializable");
+        assert_eq!(de, vec);
+        assert_eq!(de.capacity(), 10);
+        assert_eq!(de.room(), 6);
+    }
+
+    #[test]
+    fn round_trip_empty_and_full() {
+        let vec = FixedVec::<u32>::new(0);
+        let json = serde_json::to_string(&vec).expect("serializable");
+        let de: FixedVec<u32> = serde_json::from_str(&json).expect("deserializable");
+        assert_eq!(de.capacity(), 0);
+        assert!(de.is_empty());
+
+        let vec: FixedVec<_> = FixedVec::collect_exact(0..7u32);
+        let json = serde_json::to_string(&vec).expect("serializable");
+        let de: FixedVec<u32> = serde_json::from_str(&json).expect("deserializable");
+        assert_eq!(de, vec);
+        assert!(de.is_full());
+    }
+
+    #[test]
+    fn deserialize_data_before_capacity() {
+        let json = r#"{"data":[1,2,3],"capacity":5}"#;
+        let de: FixedVec<u32> = serde_json::from_str(json).expect("deserializable");
+        assert_eq!(de, &[1, 2, 3]);
+        assert_eq!(de.capacity(), 5);
+        assert_eq!(de.room(), 2);
+    }
+
+    #[test]
+    fn deserialize_from_seq() {
+        let json = r#"[4,[1,2,3]]"#;
+        let de: FixedVec<u32> = serde_json::from_str(json).expect("deserializable");
+        assert_eq!(de, &[1, 2, 3]);
+        assert_eq!(de.capacity(), 4);
+    }
+
+    #[test]
+    fn deserialize_rejects_exceeding_length() {
+        let inputs = [
+            r#"{"capacity":2,"data":[1,2,3]}"#,
+            r#"{"data":[1,2,3],"capacity":2}"#,
+            r#"[2,[1,2,3]]"#,
+        ];
+        for json in inputs {
+            let result = serde_json::from_str::<FixedVec<u32>>(json);
+            assert!(result.is_err());
+        }
+    }
+
+    #[test]
+    fn deserialize_grows_to_capacity_while_reading() {
+        let data: Vec<_> = (0..37).collect();
+        let json = serde_json::to_string(&(100, &data)).expect("serializable");
+        let de: FixedVec<u32> = serde_json::from_str(&json).expect("deserializable");
+        assert_eq!(de, &data);
+        assert_eq!(de.capacity(), 100);
+        assert_eq!(de.room(), 63);
+    }
+
+    #[test]
+    fn deserialize_reads_elements_before_allocating_capacity() {
+        let huge = usize::MAX / 4;
+
+        let json = alloc::format!(r#"{{"capacity":{},"data":[1,"x"]}}"#, huge);
+        let error = serde_json::from_str::<FixedVec<u64>>(&json).expect_err("invalid element");
+        assert!(error.to_string().contains("invalid type"));
+
+        let json = alloc::format!(r#"{{"capacity":{},"data":[]}}"#, huge);
+        assert!(serde_json::from_str::<FixedVec<u64>>(&json).is_err());
+    }
+
+    #[test]
+    fn deserialize_rejects_invalid_fields() {
+        let inputs = [
+            r#"{"data":[1,2,3]}"#,
+            r#"{"capacity":4}"#,
+            r#"{"capacity":4,"capacity":4,"data":[]}"#,
+            r#"{"capacity":4,"data":[],"len":0}"#,
+        ];
+        for json in inputs {
+            let result = serde_json::from_str::<FixedVec<u32>>(json);
+            assert!(result.is_err());
+        }
+    }
+
+    #[test]
+    fn round_trip_nested() {
+        let vec: Vec<FixedVec<u32>> = (0..3)
+            .map(|i| FixedVec::from_iter_with_capacity(4, 0..i).expect("has-room"))
+            .collect();
+        let json = serde_json::to_string(&vec).expect("serializable");
+        let de: Vec<FixedVec<u32>> = serde_json::from_str(&json).expect("deserializable");
+        assert_eq!(de.len(), 3);
+        for (a, b) in de.iter().zip(&vec) {
+            assert_eq!(a, b);
+            assert_eq!(a.capacity(), 4);
+        }
+    }
+}
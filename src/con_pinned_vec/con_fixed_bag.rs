@@ -0,0 +1,304 @@
+use crate::{ConcurrentFixedVec, FixedVec};
+use allocator_api2::alloc::{Allocator, Global};
+use core::fmt::Debug;
+use core::mem::ManuallyDrop;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+/// A safe, concurrent, push-only bag with a fixed capacity built on top of [`ConcurrentFixedVec`].
+///
+/// * Elements can be pushed concurrently through a shared reference with [`push`](Self::push),
+///   which fails by returning back the value when the bag is full.
+/// * Since the underlying storage is pinned, pushed elements never move.
+/// * The bag can be converted back into a [`FixedVec`] holding all pushed elements with [`into_inner`](Self::into_inner).
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_fixed_vec::prelude::*;
+/// use orx_fixed_vec::ConcurrentFixedBag;
+///
+/// let bag = ConcurrentFixedBag::new(100);
+///
+/// std::thread::scope(|s| {
+///     for t in 0..4 {
+///         let bag = &bag;
+///         s.spawn(move || {
+///             for i in 0..30 {
+///                 let _ = bag.push(t * 100 + i);
+///             }
+///         });
+///     }
+/// });
+///
+/// assert_eq!(bag.len(), 100);
+/// assert!(bag.is_full());
+///
+/// let vec = bag.into_inner();
+/// assert_eq!(vec.len(), 100);
+/// assert_eq!(vec.capacity(), 100);
+/// ```
+pub struct ConcurrentFixedBag<T, A = Global>
+where
+    A: Allocator,
+{
+    vec: ConcurrentFixedVec<T, A>,
+    capacity: usize,
+    // number of positions reserved by pushes, never exceeds capacity
+    reserved: AtomicUsize,
+    // number of completed writes
+    written: AtomicUsize,
+}
+
+// SAFETY: elements are only written through `push` and moved out by value; no shared access to
+// the elements is provided. Hence, the bag can be shared among threads whenever `T` can be sent.
+unsafe impl<T: Send, A: Allocator + Send> Send for ConcurrentFixedBag<T, A> {}
+
+// SAFETY: see the Send implementation; the allocator is not accessed through a shared reference.
+unsafe impl<T: Send, A: Allocator + Sync> Sync for ConcurrentFixedBag<T, A> {}
+
+impl<T> ConcurrentFixedBag<T> {
+    /// Creates a new empty bag with the given fixed capacity.
+    pub fn new(fixed_capacity: usize) -> Self {
+        FixedVec::new(fixed_capacity).into()
+    }
+}
+
+impl<T, A> From<FixedVec<T, A>> for ConcurrentFixedBag<T, A>
+where
+    A: Allocator,
+{
+    /// Converts the fixed vector into a concurrent bag; elements of the vector are kept as the first elements of the bag.
+    fn from(vec: FixedVec<T, A>) -> Self {
+        let len = vec.data.len();
+        let capacity = vec.capacity;
+        Self {
+            vec: vec.into(),
+            capacity,
+            reserved: len.into(),
+            written: len.into(),
+        }
+    }
+}
+
+impl<T, A> ConcurrentFixedBag<T, A>
+where
+    A: Allocator,
+{
+    /// Concurrently pushes the `value` to the bag:
+    /// * returns Ok of the position of the pushed element if there is available room,
+    /// * returns back the value as Err otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_fixed_vec::ConcurrentFixedBag;
+    ///
+    /// let bag = ConcurrentFixedBag::new(2);
+    /// assert_eq!(bag.push('a'), Ok(0));
+    /// assert_eq!(bag.push('b'), Ok(1));
+    /// assert_eq!(bag.push('c'), Err('c'));
+    /// ```
+    pub fn push(&self, value: T) -> Result<usize, T> {
+        let capacity = self.capacity;
+        let reserved = self
+            .reserved
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |n| {
+                (n < capacity).then_some(n + 1)
+            });
+
+        match reserved {
+            Ok(idx) => {
+                // SAFETY: idx < capacity, and it is reserved exclusively for this push
+                unsafe { self.vec.write(idx, value) };
+                self.written.fetch_add(1, Ordering::Release);
+                Ok(idx)
+            }
+            Err(_) => Err(value),
+        }
+    }
+
+    /// Returns the number of elements whose writes are completed.
+    ///
+    /// Note that concurrent pushes might be in progress; therefore, the returned value is a snapshot.
+    pub fn len(&self) -> usize {
+        self.written.load(Ordering::Acquire)
+    }
+
+    /// Returns true if there exists no completed write.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the fixed capacity of the bag.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns true if all positions of the bag are reserved by pushes; i.e., a subsequent push will fail.
+    pub fn is_full(&self) -> bool {
+        self.reserved.load(Ordering::Acquire) == self.capacity
+    }
+
+    /// Converts the bag into a fixed vector containing all pushed elements,
+    /// with the same fixed capacity as the bag.
+    pub fn into_inner(self) -> FixedVec<T, A> {
+        let mut bag = ManuallyDrop::new(self);
+        let len = *bag.written.get_mut();
+        // SAFETY: bag is not dropped, vec is moved out exactly once
+        let vec = unsafe { core::ptr::read(&bag.vec) };
+        // SAFETY: having the bag by value, all pushes are completed; hence, exactly the first len positions are written
+        unsafe { vec.into_fixed_vec(len) }
+    }
+}
+
+impl<T, A> Drop for ConcurrentFixedBag<T, A>
+where
+    A: Allocator,
+{
+    fn drop(&mut self) {
+        let len = *self.written.get_mut();
+        // SAFETY: having exclusive access, all pushes are completed; hence, exactly the first len positions are written
+        unsafe { self.vec.clear_elements(len) };
+    }
+}
+
+impl<T, A> Debug for ConcurrentFixedBag<T, A>
+where
+    A: Allocator,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ConcurrentFixedBag")
+            .field("len", &self.len())
+            .field("fixed_capacity", &self.capacity)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ConcurrentFixedBag;
+    use crate::helpers::drop_counter::DropCounter;
+    use crate::prelude::*;
+    use alloc::string::{String, ToString};
+    use alloc::vec::Vec;
+
+    #[test]
+    fn push_until_full() {
+        let bag = ConcurrentFixedBag::new(3);
+        assert!(bag.is_empty());
+
+        for i in 0..3 {
+            assert_eq!(bag.push(i.to_string()), Ok(i));
+            assert_eq!(bag.len(), i + 1);
+        }
+        assert!(bag.is_full());
+        assert_eq!(bag.push("x".to_string()), Err("x".to_string()));
+        assert_eq!(bag.len(), 3);
+
+        let vec = bag.into_inner();
+        assert_eq!(vec, ["0", "1", "2"].map(String::from));
+        assert_eq!(vec.capacity(), 3);
+    }
+
+    #[test]
+    fn from_fixed_vec() {
+        let mut vec = FixedVec::new(4);
+        vec.push('a');
+
+        let bag = ConcurrentFixedBag::from(vec);
+        assert_eq!(bag.len(), 1);
+        assert_eq!(bag.push('b'), Ok(1));
+
+        let vec = bag.into_inner();
+        assert_eq!(vec, &['a', 'b']);
+        assert_eq!(vec.room(), 2);
+    }
+
+    #[test]
+    fn zero_capacity() {
+        let bag = ConcurrentFixedBag::new(0);
+        assert!(bag.is_full());
+        assert_eq!(bag.push(42), Err(42));
+        assert!(bag.into_inner().is_empty());
+    }
+
+    #[test]
+    fn concurrent_push() {
+        let num_threads = 8;
+        let num_items_per_thread = 1000;
+        let capacity = 5000;
+
+        let bag = ConcurrentFixedBag::new(capacity);
+        let rejected: Vec<Vec<usize>> = std::thread::scope(|s| {
+            let handles: Vec<_> = (0..num_threads)
+                .map(|t| {
+                    let bag = &bag;
+                    s.spawn(move || {
+                        (0..num_items_per_thread)
+                            .map(|i| t * num_items_per_thread + i)
+                            .filter_map(|x| bag.push(x).err())
+                            .collect::<Vec<_>>()
+                    })
+                })
+                .collect();
+            handles
+                .into_iter()
+                .map(|h| h.join().expect("no-panic"))
+                .collect()
+        });
+
+        assert_eq!(bag.len(), capacity);
+        let vec = bag.into_inner();
+        assert_eq!(vec.len(), capacity);
+
+        let mut all: Vec<_> = vec
+            .iter()
+            .copied()
+            .chain(rejected.into_iter().flatten())
+            .collect();
+        all.sort();
+        assert_eq!(
+            all,
+            (0..num_threads * num_items_per_thread).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn drop_drops_pushed_elements() {
+        let counter = DropCounter::new();
+
+        let bag = ConcurrentFixedBag::new(10);
+        std::thread::scope(|s| {
+            for _ in 0..3 {
+                s.spawn(|| {
+                    for _ in 0..2 {
+                        let _ = bag.push(counter.track());
+                    }
+                });
+            }
+        });
+        assert_eq!(counter.dropped(), 0);
+
+        drop(bag);
+        assert_eq!(counter.dropped(), 6);
+    }
+
+    #[test]
+    fn into_inner_transfers_ownership() {
+        let counter = DropCounter::new();
+
+        let bag = ConcurrentFixedBag::new(4);
+        for _ in 0..5 {
+            let _ = bag.push(counter.track());
+        }
+        // the rejected element is dropped
+        assert_eq!(counter.dropped(), 1);
+
+        let vec = bag.into_inner();
+        assert_eq!(vec.len(), 4);
+        assert_eq!(counter.dropped(), 1);
+
+        drop(vec);
+        assert_eq!(counter.dropped(), 5);
+    }
+}
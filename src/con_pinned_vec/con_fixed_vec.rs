@@ -1,22 +1,99 @@
 use crate::{
     FixedVec,
     common_traits::ptr_iter::FixedVecPtrIter,
-    con_pinned_vec::into_iter::ConcurrentFixedVecIntoIter,
-    helpers::range::{range_end, range_start},
+    con_pinned_vec::{into_iter::ConcurrentFixedVecIntoIter, slot_bitmap::SlotBitmap},
+    helpers::{
+        fill::fill_range_with,
+        range::{range_end, range_start},
+        vec::is_zst,
+    },
+};
+use allocator_api2::{
+    alloc::{Allocator, Global},
+    vec::Vec as AllocVec,
 };
-use alloc::vec::Vec;
 use core::fmt::Debug;
+use core::ptr::NonNull;
 use core::{cmp::Ordering, ops::Range};
 use orx_pinned_vec::{ConcurrentPinnedVec, PinnedVecGrowthError};
 
 /// Concurrent wrapper ([`orx_pinned_vec::ConcurrentPinnedVec`]) for the `FixedVec`.
-pub struct ConcurrentFixedVec<T> {
-    data: Vec<T>,
-    ptr: *const T,
+///
+/// Elements of the vector are never moved: the maximum capacity can be increased only while the vector is empty.
+///
+/// # Thread safety
+///
+/// `ConcurrentFixedVec<T, A>` is `Send` if `T: Send` and `A: Send`;
+/// and it is `Sync` if `T: Send + Sync` and `A: Sync`.
+/// `T: Send` is required for sharing since elements can be written through a shared reference,
+/// and `T: Sync` since references to the elements can be obtained through a shared reference.
+///
+/// The contract on which methods may run concurrently is as follows:
+///
+/// * Methods taking `&self` may be called concurrently from multiple threads:
+///   `capacity`, `max_capacity`, `grow_to`, `grow_to_and_fill_with`, `get`, `get_ptr_mut`, `slices`, `slices_mut`,
+///   `iter`, `iter_over_range`, `ptr_iter_unchecked`, `fill_with` and `clone_with_len`.
+///   The allocation never changes while a shared reference exists; hence, pointers obtained by these methods
+///   stay valid concurrently.
+///   However, the vector does not synchronize accesses to the elements. The caller must guarantee that:
+///   * a position is written by at most one thread, and it is not read or referenced by another thread
+///     while it is being written; and
+///   * a position is read only after its write has completed and the write *happens-before* the read,
+///     e.g., by synchronizing through an atomic counter.
+/// * Methods taking `&mut self` or `self`, such as `reserve_maximum_concurrent_capacity`, `set_pinned_vec_len`,
+///   `get_mut`, `iter_mut`, `clear`, `into_inner` and `into_iter`, have exclusive access; and hence,
+///   never run concurrently with any other method.
+///
+/// # Debug checks
+///
+/// When the `debug-checks` feature is enabled, the vector keeps track of its initialized positions in a bitmap,
+/// and it panics on the following misuses which are otherwise undefined behavior or memory leaks:
+///
+/// * reading a position which is not initialized yet, through `get` or `get_mut`;
+/// * reading a position which is neither initialized nor reserved, through `slices`, `slices_mut`, `iter`,
+///   `iter_over_range`, `iter_mut` or `into_iter`;
+/// * writing an initialized or reserved position through [`write`](Self::write) or `fill_with`,
+///   or marking an initialized position as written by [`mark_written`](Self::mark_written); and
+/// * declaring a length which includes positions which are neither initialized nor reserved, by `into_inner`,
+///   `clone_with_len`, `set_pinned_vec_len` or `clear`.
+///
+/// A position is initialized if it is within the length of the vector that the concurrent vector is created from,
+/// if it is filled by `fill_with` or `reserve_maximum_concurrent_capacity_fill_with`, or once it is written by
+/// `write`. A position written through the pointer obtained by `get_ptr_mut` is only reserved, since the vector
+/// cannot observe when the write completes; the caller might call [`mark_written`](Self::mark_written) after
+/// the write to mark it as initialized. Note that the reserved positions are accepted by the methods declaring a
+/// length, which callers of `get_ptr_mut` rely on; and that obtaining the pointer to a position more than once
+/// is never reported as a double write, since pre-filled positions might be overwritten in place.
+/// `ptr_iter_unchecked` is not checked.
+/// Positions of zero-sized types are not tracked.
+/// The feature is meant for test builds; when it is disabled, the checks are compiled away.
+///
+/// # Drop
+///
+/// Dropping the concurrent vector drops the elements within `0..len`, where `len` is the length of the
+/// vector it is created from, or the length set by `set_pinned_vec_len` or `clear`. Elements written
+/// concurrently beyond this length are leaked unless the vector is converted back by `into_inner`
+/// or cleared by `clear` with the correct length.
+pub struct ConcurrentFixedVec<T, A: Allocator = Global> {
+    // owns the allocation and the elements within 0..data.len();
+    // never dereferenced, elements are accessed only through `ptr`
+    data: AllocVec<T, A>,
+    // pointer to the allocation obtained once from `data` without creating a reference
+    ptr: NonNull<T>,
     current_capacity: usize,
+    generation: usize,
+    // initialized positions; zero-sized and no-op unless the `debug-checks` feature is enabled
+    initialized: SlotBitmap,
 }
 
-impl<T> Debug for ConcurrentFixedVec<T> {
+// SAFETY: the vector owns its elements and its allocator, the pointer points to its own allocation.
+unsafe impl<T: Send, A: Allocator + Send> Send for ConcurrentFixedVec<T, A> {}
+
+// SAFETY: through a shared reference, elements might be written (T: Send) and referenced (T: Sync),
+// and the allocator might be cloned (A: Sync). See the thread safety contract above for the accesses to the elements.
+unsafe impl<T: Send + Sync, A: Allocator + Sync> Sync for ConcurrentFixedVec<T, A> {}
+
+impl<T, A: Allocator> Debug for ConcurrentFixedVec<T, A> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("ConcurrentFixedVec")
             .field("fixed_capacity", &self.current_capacity)
@@ -24,22 +101,151 @@ impl<T> Debug for ConcurrentFixedVec<T> {
     }
 }
 
-impl<T> From<FixedVec<T>> for ConcurrentFixedVec<T> {
-    fn from(value: FixedVec<T>) -> Self {
-        let mut data = value.data;
-        let current_capacity = data.capacity();
-        unsafe { data.set_len(current_capacity) };
-        let ptr = data.as_mut_ptr();
+impl<T, A: Allocator> From<FixedVec<T, A>> for ConcurrentFixedVec<T, A> {
+    fn from(value: FixedVec<T, A>) -> Self {
+        let (mut data, current_capacity) = (value.data, value.capacity);
+        let ptr = vec_ptr(&mut data);
+        let initialized = SlotBitmap::new::<T>(current_capacity, data.len());
         Self {
             data,
             ptr,
             current_capacity,
+            generation: value.generation,
+            initialized,
         }
     }
 }
 
-impl<T> ConcurrentPinnedVec<T> for ConcurrentFixedVec<T> {
-    type P = FixedVec<T>;
+impl<T, A: Allocator> ConcurrentFixedVec<T, A> {
+    /// Reallocates the empty vector so that its capacity is at least `new_maximum_capacity`,
+    /// and refreshes the cached pointer to the new allocation.
+    ///
+    /// The vector must be empty; i.e., its length must be zero, as set by `clear` or `set_pinned_vec_len`.
+    /// Otherwise, the leftover elements are dropped rather than moved to the new allocation.
+    fn grow_empty_to(&mut self, new_maximum_capacity: usize) {
+        debug_assert_eq!(self.data.len(), 0, "{}", ERR_MSG_GROW_NON_EMPTY);
+        self.data.clear();
+        self.data.reserve(new_maximum_capacity);
+
+        self.ptr = vec_ptr(&mut self.data);
+        self.current_capacity = match is_zst::<T>() {
+            true => new_maximum_capacity,
+            false => self.data.capacity(),
+        };
+        // reallocation copies the entire previous allocation, including the positions written beyond the length
+        self.initialized.grow(self.current_capacity);
+    }
+
+    /// Writes the `value` to the uninitialized position at the given `index`.
+    ///
+    /// This is equivalent to writing through the pointer obtained by `get_ptr_mut`; however, when the
+    /// `debug-checks` feature is enabled, the position is marked as initialized only after the `value` is
+    /// written, and writing a position which is already initialized or reserved is reported as a double write.
+    ///
+    /// # Safety
+    ///
+    /// The position at `index` must not be initialized, since its current value would be overwritten
+    /// without being dropped, and it must not be accessed by other threads while it is being written.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds of the capacity.
+    ///
+    /// When the `debug-checks` feature is enabled, panics if the position is already initialized or reserved.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_fixed_vec::prelude::*;
+    /// use orx_pinned_vec::ConcurrentPinnedVec;
+    ///
+    /// let con_vec = FixedVec::new(4).into_concurrent();
+    /// unsafe { con_vec.write(0, 'a') };
+    /// unsafe { con_vec.write(1, 'b') };
+    ///
+    /// let vec = unsafe { con_vec.into_inner(2) };
+    /// assert_eq!(vec, &['a', 'b']);
+    /// ```
+    pub unsafe fn write(&self, index: usize, value: T) {
+        assert!(index < self.current_capacity);
+        self.initialized.assert_uninitialized(index..index + 1);
+        // SAFETY: index is in bounds, and the position is exclusively written by the caller
+        unsafe { self.ptr_at(index).write(value) };
+        self.initialized.mark_written(index);
+    }
+
+    /// Converts the concurrent vector back into a fixed vector with the given length.
+    ///
+    /// # Safety
+    ///
+    /// Exactly the positions within `0..len` must be initialized.
+    pub(crate) unsafe fn into_fixed_vec(mut self, len: usize) -> FixedVec<T, A> {
+        self.initialized.assert_initialized_or_reserved(0..len);
+        unsafe { self.data.set_len(len) };
+        let mut vec = FixedVec::from_parts(self.data, self.current_capacity);
+        vec.generation = self.generation;
+        vec
+    }
+
+    /// Drops the elements within `0..prior_len` and marks all positions as uninitialized.
+    ///
+    /// # Safety
+    ///
+    /// Exactly the positions within `0..prior_len` must be initialized.
+    pub(crate) unsafe fn clear_elements(&mut self, prior_len: usize) {
+        self.initialized
+            .assert_initialized_or_reserved(0..prior_len);
+        unsafe { self.data.set_len(prior_len) };
+        self.generation = self.generation.wrapping_add(1);
+        self.data.clear();
+        self.initialized = SlotBitmap::new::<T>(self.current_capacity, 0);
+    }
+
+    /// Marks the position at the given `index`, which has just been written through the pointer obtained by
+    /// `get_ptr_mut`, as initialized.
+    ///
+    /// This is a no-op unless the `debug-checks` feature is enabled, in which case the position can afterwards
+    /// be read by `get` and `get_mut`, and a second write to the position is reported.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds of the capacity.
+    ///
+    /// When the `debug-checks` feature is enabled, panics if the position is already initialized.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_fixed_vec::prelude::*;
+    /// use orx_pinned_vec::ConcurrentPinnedVec;
+    ///
+    /// let con_vec = FixedVec::<char>::new(4).into_concurrent();
+    /// unsafe { con_vec.get_ptr_mut(0).write('a') };
+    /// con_vec.mark_written(0);
+    ///
+    /// assert_eq!(unsafe { con_vec.get(0) }, Some(&'a'));
+    /// ```
+    pub fn mark_written(&self, index: usize) {
+        assert!(index < self.current_capacity);
+        self.initialized.mark_written(index);
+    }
+
+    #[inline(always)]
+    fn ptr_at(&self, index: usize) -> *mut T {
+        // SAFETY: callers guarantee that index is within 0..=capacity
+        unsafe { self.ptr.add(index).as_ptr() }
+    }
+}
+
+/// Returns the pointer to the allocation of the `vec` without creating an intermediate reference to its elements.
+#[inline(always)]
+fn vec_ptr<T, A: Allocator>(vec: &mut AllocVec<T, A>) -> NonNull<T> {
+    // SAFETY: pointer of a vector is never null, it is dangling when the vector does not allocate
+    unsafe { NonNull::new_unchecked(vec.as_mut_ptr()) }
+}
+
+impl<T, A: Allocator + Clone + Default> ConcurrentPinnedVec<T> for ConcurrentFixedVec<T, A> {
+    type P = FixedVec<T, A>;
 
     type SliceIter<'a>
         = Option<&'a [T]>
@@ -58,11 +264,10 @@ impl<T> ConcurrentPinnedVec<T> for ConcurrentFixedVec<T> {
     where
         Self: 'a;
 
-    type IntoIter = ConcurrentFixedVecIntoIter<T>;
+    type IntoIter = ConcurrentFixedVecIntoIter<T, A>;
 
-    unsafe fn into_inner(mut self, len: usize) -> Self::P {
-        unsafe { self.data.set_len(len) };
-        self.data.into()
+    unsafe fn into_inner(self, len: usize) -> Self::P {
+        unsafe { self.into_fixed_vec(len) }
     }
 
     unsafe fn clone_with_len(&self, len: usize) -> Self
@@ -70,11 +275,12 @@ impl<T> ConcurrentPinnedVec<T> for ConcurrentFixedVec<T> {
         T: Clone,
     {
         assert!(len <= self.capacity());
-        let mut clone = Vec::with_capacity(self.capacity());
-        for i in 0..len {
-            clone.push(self.data[i].clone());
-        }
-        FixedVec::from(clone).into()
+        self.initialized.assert_initialized_or_reserved(0..len);
+        let mut clone = AllocVec::with_capacity_in(self.capacity(), self.data.allocator().clone());
+        // SAFETY: positions within 0..len are written
+        let slice = unsafe { core::slice::from_raw_parts(self.ptr.as_ptr(), len) };
+        clone.extend_from_slice(slice);
+        FixedVec::from_parts(clone, self.capacity()).into()
     }
 
     fn capacity(&self) -> usize {
@@ -106,13 +312,19 @@ impl<T> ConcurrentPinnedVec<T> for ConcurrentFixedVec<T> {
         }
     }
 
+    /// Writes `fill_with()` into every position within `range`.
+    ///
+    /// If `fill_with` panics, the elements written so far by this call are dropped before the panic propagates;
+    /// hence, the positions within `range` are left uninitialized rather than leaked.
     fn fill_with<F>(&self, range: core::ops::Range<usize>, fill_with: F)
     where
         F: Fn() -> T,
     {
-        for i in range {
-            unsafe { self.get_ptr_mut(i).write(fill_with()) };
-        }
+        self.initialized.assert_uninitialized(range.clone());
+        // SAFETY: positions within `range` are uninitialized and are not accessed by other threads, as required
+        // by the thread safety contract; drop guard of `fill_range_with` handles panics of `fill_with`
+        unsafe { fill_range_with(self.ptr.as_ptr(), self.capacity(), range.clone(), fill_with) };
+        self.initialized.mark_initialized_range(range);
     }
 
     fn slices<R: core::ops::RangeBounds<usize>>(
@@ -128,8 +340,8 @@ impl<T> ConcurrentPinnedVec<T> for ConcurrentFixedVec<T> {
                 (Ordering::Equal | Ordering::Greater, _) => None,
                 (_, Ordering::Greater) => None,
                 _ => {
-                    let p = unsafe { self.ptr.add(a) };
-                    let slice = unsafe { core::slice::from_raw_parts(p, b - a) };
+                    self.initialized.assert_initialized_or_reserved(a..b);
+                    let slice = unsafe { core::slice::from_raw_parts(self.ptr_at(a), b - a) };
                     Some(slice)
                 }
             },
@@ -149,8 +361,8 @@ impl<T> ConcurrentPinnedVec<T> for ConcurrentFixedVec<T> {
                 (Ordering::Equal | Ordering::Greater, _) => None,
                 (_, Ordering::Greater) => None,
                 _ => {
-                    let p = unsafe { self.ptr.add(a) };
-                    let slice = unsafe { core::slice::from_raw_parts_mut(p as *mut T, b - a) };
+                    self.initialized.assert_initialized_or_reserved(a..b);
+                    let slice = unsafe { core::slice::from_raw_parts_mut(self.ptr_at(a), b - a) };
                     Some(slice)
                 }
             },
@@ -161,8 +373,8 @@ impl<T> ConcurrentPinnedVec<T> for ConcurrentFixedVec<T> {
     where
         T: 'a,
     {
-        let p = self.data.as_ptr();
-        let slice = unsafe { core::slice::from_raw_parts(p, len) };
+        self.initialized.assert_initialized_or_reserved(0..len);
+        let slice = unsafe { core::slice::from_raw_parts(self.ptr.as_ptr(), len) };
         slice.iter()
     }
 
@@ -174,9 +386,8 @@ impl<T> ConcurrentPinnedVec<T> for ConcurrentFixedVec<T> {
         T: 'a,
     {
         let [a, b] = orx_pinned_vec::utils::slice::vec_range_limits(&range, None);
-        let len = b - a;
-        let p = unsafe { self.data.as_ptr().add(a) };
-        let slice = unsafe { core::slice::from_raw_parts(p, len) };
+        self.initialized.assert_initialized_or_reserved(a..b);
+        let slice = unsafe { core::slice::from_raw_parts(self.ptr_at(a), b - a) };
         slice.iter()
     }
 
@@ -184,48 +395,65 @@ impl<T> ConcurrentPinnedVec<T> for ConcurrentFixedVec<T> {
     where
         T: 'a,
     {
-        let p = self.data.as_mut_ptr();
-        let slice = unsafe { core::slice::from_raw_parts_mut(p, len) };
+        self.initialized.assert_initialized_or_reserved(0..len);
+        let slice = unsafe { core::slice::from_raw_parts_mut(self.ptr.as_ptr(), len) };
         slice.iter_mut()
     }
 
     unsafe fn set_pinned_vec_len(&mut self, len: usize) {
+        self.initialized.assert_initialized_or_reserved(0..len);
         unsafe { self.data.set_len(len) };
     }
 
     unsafe fn get(&self, index: usize) -> Option<&T> {
         match index < self.capacity() {
-            true => Some(unsafe { &*self.ptr.add(index) }),
+            true => {
+                self.initialized.assert_initialized(index..index + 1);
+                Some(unsafe { &*self.ptr_at(index) })
+            }
             false => None,
         }
     }
 
     unsafe fn get_mut(&mut self, index: usize) -> Option<&mut T> {
         match index < self.capacity() {
-            true => Some(&mut self.data[index]),
+            true => {
+                self.initialized.assert_initialized(index..index + 1);
+                Some(unsafe { &mut *self.ptr_at(index) })
+            }
             false => None,
         }
     }
 
     unsafe fn get_ptr_mut(&self, index: usize) -> *mut T {
         assert!(index < self.capacity());
-        unsafe { self.ptr.add(index) as *mut T }
+        self.initialized.mark_reserved(index);
+        self.ptr_at(index)
     }
 
+    /// Increases the maximum capacity to at least `new_maximum_capacity` only if the vector is empty,
+    /// and returns the resulting maximum capacity.
+    ///
+    /// The capacity of a fixed vector can only be increased by moving its elements to a new allocation.
+    /// Therefore, in order to keep the elements pinned, the capacity is kept unchanged whenever the vector
+    /// contains elements, i.e., `current_len > 0`. In this case, the returned capacity is less than
+    /// `new_maximum_capacity`.
     unsafe fn reserve_maximum_concurrent_capacity(
         &mut self,
-        _: usize,
+        current_len: usize,
         new_maximum_capacity: usize,
     ) -> usize {
-        let additional = new_maximum_capacity.saturating_sub(self.capacity());
-        self.data.reserve(additional);
-
-        let new_capacity = self.data.capacity();
-        self.current_capacity = new_capacity;
-
-        new_capacity
+        if current_len == 0 && new_maximum_capacity > self.capacity() {
+            self.grow_empty_to(new_maximum_capacity);
+        }
+        self.current_capacity
     }
 
+    /// Increases the maximum capacity to at least `new_maximum_capacity` only if the vector is empty,
+    /// fills all positions of the new allocation with `fill_with()`, and returns the resulting maximum capacity.
+    ///
+    /// If `fill_with` panics, the elements created so far are dropped and the vector is left empty,
+    /// consistent with `current_len == 0`.
     unsafe fn reserve_maximum_concurrent_capacity_fill_with<F>(
         &mut self,
         current_len: usize,
@@ -235,32 +463,32 @@ impl<T> ConcurrentPinnedVec<T> for ConcurrentFixedVec<T> {
     where
         F: Fn() -> T,
     {
-        let additional = new_maximum_capacity.saturating_sub(self.capacity());
-        self.data.reserve(additional);
-
-        self.current_capacity = self.data.capacity();
-
-        unsafe { self.data.set_len(current_len) };
-
-        for _ in current_len..self.current_capacity {
-            self.data.push(fill_with());
+        if current_len == 0 && new_maximum_capacity > self.capacity() {
+            self.grow_empty_to(new_maximum_capacity);
+
+            // length is set only after all positions are written; elements are dropped if `fill_with` panics
+            let capacity = self.current_capacity;
+            unsafe { fill_range_with(self.ptr.as_ptr(), capacity, 0..capacity, fill_with) };
+            self.initialized.mark_initialized_range(0..capacity);
+            unsafe { self.data.set_len(capacity) };
         }
-
         self.current_capacity
     }
 
     unsafe fn clear(&mut self, prior_len: usize) {
-        unsafe { self.set_pinned_vec_len(prior_len) };
-        self.data.clear()
+        unsafe { self.clear_elements(prior_len) };
     }
 
     unsafe fn ptr_iter_unchecked(&self, range: Range<usize>) -> Self::PtrIter<'_> {
-        let ptr = self.ptr as *mut T;
-        let ptr = unsafe { ptr.add(range.start) };
+        let ptr = unsafe { self.ptr.add(range.start) };
         FixedVecPtrIter::new(ptr, range.len())
     }
 
     unsafe fn into_iter(self, range: Range<usize>) -> Self::IntoIter {
+        self.initialized
+            .assert_initialized_or_reserved(range.clone());
         ConcurrentFixedVecIntoIter::new(self.data, range)
     }
 }
+
+const ERR_MSG_GROW_NON_EMPTY: &str = "ConcurrentFixedVec can only be reallocated when it is empty; its length must be set to zero first.";
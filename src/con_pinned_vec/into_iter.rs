@@ -1,21 +1,33 @@
-use alloc::vec::Vec;
+use allocator_api2::{
+    alloc::{Allocator, Global},
+    vec::Vec as AllocVec,
+};
 use core::ops::Range;
+use core::ptr::NonNull;
 
-pub struct ConcurrentFixedVecIntoIter<T> {
-    // will be dropped with the iter
-    _data: Vec<T>,
-    begin: *mut T,
+pub struct ConcurrentFixedVecIntoIter<T, A: Allocator = Global> {
+    // empty vector deallocating the memory when dropped with the iter; never dereferenced
+    _data: AllocVec<T, A>,
+    // pointer to the allocation obtained once from `_data`, elements are read only through this pointer
+    begin: NonNull<T>,
     current: usize,
     end_exclusive: usize,
 }
 
-impl<T> ConcurrentFixedVecIntoIter<T> {
-    pub(super) fn new(mut data: Vec<T>, range: Range<usize>) -> Self {
+// SAFETY: the iterator owns the remaining elements and the allocation.
+unsafe impl<T: Send, A: Allocator + Send> Send for ConcurrentFixedVecIntoIter<T, A> {}
+
+// SAFETY: no access to the elements or the allocator is provided through a shared reference.
+unsafe impl<T: Sync, A: Allocator + Sync> Sync for ConcurrentFixedVecIntoIter<T, A> {}
+
+impl<T, A: Allocator> ConcurrentFixedVecIntoIter<T, A> {
+    pub(super) fn new(mut data: AllocVec<T, A>, range: Range<usize>) -> Self {
         // SAFETY: data contains items to be dropped within range
         // remaining positions are not initialized or already moved out
         unsafe { data.set_len(0) };
         let (current, end_exclusive) = (range.start, range.end);
-        let begin = data.as_mut_ptr();
+        // SAFETY: pointer of a vector is never null
+        let begin = unsafe { NonNull::new_unchecked(data.as_mut_ptr()) };
         Self {
             _data: data,
             begin,
@@ -25,19 +37,21 @@ impl<T> ConcurrentFixedVecIntoIter<T> {
     }
 }
 
-impl<T> Drop for ConcurrentFixedVecIntoIter<T> {
+impl<T, A: Allocator> Drop for ConcurrentFixedVecIntoIter<T, A> {
     fn drop(&mut self) {
         if core::mem::needs_drop::<T>() {
-            for i in self.current..self.end_exclusive {
-                // SAFETY: begin + i is in bounds
-                let ptr = unsafe { self.begin.add(i) };
-                unsafe { ptr.drop_in_place() };
-            }
+            // SAFETY: positions within current..end_exclusive are in bounds and not yet moved out
+            let remaining = core::ptr::slice_from_raw_parts_mut(
+                unsafe { self.begin.add(self.current).as_ptr() },
+                self.end_exclusive - self.current,
+            );
+            self.current = self.end_exclusive;
+            unsafe { remaining.drop_in_place() };
         }
     }
 }
 
-impl<T> Iterator for ConcurrentFixedVecIntoIter<T> {
+impl<T, A: Allocator> Iterator for ConcurrentFixedVecIntoIter<T, A> {
     type Item = T;
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -58,7 +72,7 @@ impl<T> Iterator for ConcurrentFixedVecIntoIter<T> {
     }
 }
 
-impl<T> ExactSizeIterator for ConcurrentFixedVecIntoIter<T> {
+impl<T, A: Allocator> ExactSizeIterator for ConcurrentFixedVecIntoIter<T, A> {
     fn len(&self) -> usize {
         self.end_exclusive - self.current
     }
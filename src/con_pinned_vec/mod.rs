@@ -1,7 +1,10 @@
 #[cfg(test)]
 mod tests;
 
+mod con_fixed_bag;
 mod con_fixed_vec;
 mod into_iter;
+mod slot_bitmap;
 
+pub use con_fixed_bag::ConcurrentFixedBag;
 pub use con_fixed_vec::ConcurrentFixedVec;
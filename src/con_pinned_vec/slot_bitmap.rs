@@ -0,0 +1,208 @@
+//! Tracking of the initialized slots of the [`ConcurrentFixedVec`](crate::ConcurrentFixedVec).
+//!
+//! With the `debug-checks` feature, `SlotBitmap` keeps one bit per slot which is set when the slot is initialized,
+//! and another bit which is set when a pointer to the slot is handed out by `get_ptr_mut`.
+//! It asserts on reads of uninitialized slots and on writes to initialized or reserved slots.
+//! Otherwise, it is a zero-sized type with no-op methods; hence, it has no cost.
+
+use core::ops::Range;
+
+#[cfg(feature = "debug-checks")]
+pub(crate) use checked::SlotBitmap;
+
+#[cfg(not(feature = "debug-checks"))]
+pub(crate) use unchecked::SlotBitmap;
+
+#[cfg(feature = "debug-checks")]
+mod checked {
+    use super::*;
+    use crate::helpers::vec::is_zst;
+    use alloc::vec::Vec;
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    const BITS: usize = usize::BITS as usize;
+
+    /// Two bits per slot; one set once the slot is initialized, and one set once a pointer to the slot is
+    /// handed out to be written by the caller.
+    ///
+    /// Slots of zero-sized types are not tracked, since they do not have any memory to initialize.
+    pub(crate) struct SlotBitmap {
+        words: Vec<AtomicUsize>,
+        reserved: Vec<AtomicUsize>,
+        tracked: bool,
+    }
+
+    impl SlotBitmap {
+        /// Creates the bitmap of `num_slots` slots, where the first `num_initialized` slots are initialized.
+        pub(crate) fn new<T>(num_slots: usize, num_initialized: usize) -> Self {
+            let tracked = !is_zst::<T>();
+            let num_words = match tracked {
+                true => num_slots.div_ceil(BITS),
+                false => 0,
+            };
+            let bitmap = Self {
+                words: (0..num_words).map(|_| AtomicUsize::new(0)).collect(),
+                reserved: (0..num_words).map(|_| AtomicUsize::new(0)).collect(),
+                tracked,
+            };
+            bitmap.mark_initialized_range(0..num_initialized);
+            bitmap
+        }
+
+        /// Extends the bitmap to `num_slots` slots, keeping the bits of the existing slots.
+        pub(crate) fn grow(&mut self, num_slots: usize) {
+            if self.tracked {
+                let num_words = num_slots.div_ceil(BITS);
+                self.words.resize_with(num_words, || AtomicUsize::new(0));
+                self.reserved.resize_with(num_words, || AtomicUsize::new(0));
+            }
+        }
+
+        #[inline(always)]
+        fn word_and_mask(&self, slot: usize) -> (&AtomicUsize, usize) {
+            (&self.words[slot / BITS], 1 << (slot % BITS))
+        }
+
+        fn is_initialized(&self, slot: usize) -> bool {
+            let (word, mask) = self.word_and_mask(slot);
+            word.load(Ordering::Acquire) & mask != 0
+        }
+
+        fn is_reserved(&self, slot: usize) -> bool {
+            self.reserved[slot / BITS].load(Ordering::Acquire) & (1 << (slot % BITS)) != 0
+        }
+
+        /// Marks the `slot` as reserved to be written through a pointer handed out to the caller,
+        /// without marking it as initialized.
+        pub(crate) fn mark_reserved(&self, slot: usize) {
+            if self.tracked {
+                self.reserved[slot / BITS].fetch_or(1 << (slot % BITS), Ordering::AcqRel);
+            }
+        }
+
+        /// Marks the `slot` as initialized, regardless of whether it is already initialized or not.
+        fn mark_initialized(&self, slot: usize) {
+            if self.tracked {
+                let (word, mask) = self.word_and_mask(slot);
+                word.fetch_or(mask, Ordering::AcqRel);
+            }
+        }
+
+        /// Marks all slots within `range` as initialized.
+        pub(crate) fn mark_initialized_range(&self, range: Range<usize>) {
+            if self.tracked {
+                for slot in range {
+                    self.mark_initialized(slot);
+                }
+            }
+        }
+
+        /// Marks the `slot`, which has just been written, as initialized.
+        ///
+        /// # Panics
+        ///
+        /// Panics if the slot is already initialized, i.e., if it is concurrently written by another thread.
+        pub(crate) fn mark_written(&self, slot: usize) {
+            if self.tracked {
+                let (word, mask) = self.word_and_mask(slot);
+                let previous = word.fetch_or(mask, Ordering::AcqRel);
+                assert!(previous & mask == 0, "{} {}", ERR_MSG_DOUBLE_WRITE, slot);
+            }
+        }
+
+        /// # Panics
+        ///
+        /// Panics if any of the slots within `range` is initialized or reserved.
+        pub(crate) fn assert_uninitialized(&self, range: Range<usize>) {
+            if self.tracked {
+                for slot in range {
+                    assert!(
+                        !self.is_initialized(slot) && !self.is_reserved(slot),
+                        "{} {}",
+                        ERR_MSG_DOUBLE_WRITE,
+                        slot
+                    );
+                }
+            }
+        }
+
+        /// # Panics
+        ///
+        /// Panics if any of the slots within `range` is not initialized.
+        pub(crate) fn assert_initialized(&self, range: Range<usize>) {
+            if self.tracked {
+                for slot in range {
+                    assert!(
+                        self.is_initialized(slot),
+                        "{} {}",
+                        ERR_MSG_UNINITIALIZED,
+                        slot
+                    );
+                }
+            }
+        }
+
+        /// Similar to `assert_initialized`, except that the reserved slots are assumed to be written by the
+        /// caller through the handed out pointers; used where the caller claims the length of the vector.
+        ///
+        /// # Panics
+        ///
+        /// Panics if any of the slots within `range` is neither initialized nor reserved.
+        pub(crate) fn assert_initialized_or_reserved(&self, range: Range<usize>) {
+            if self.tracked {
+                for slot in range {
+                    assert!(
+                        self.is_initialized(slot) || self.is_reserved(slot),
+                        "{} {}",
+                        ERR_MSG_UNINITIALIZED,
+                        slot
+                    );
+                }
+            }
+        }
+    }
+
+    const ERR_MSG_DOUBLE_WRITE: &str =
+        "debug-checks: ConcurrentFixedVec slot is written more than once; slot:";
+
+    const ERR_MSG_UNINITIALIZED: &str =
+        "debug-checks: ConcurrentFixedVec slot is read before being written; slot:";
+}
+
+#[cfg(not(feature = "debug-checks"))]
+mod unchecked {
+    use super::*;
+
+    /// No-op counterpart of the bitmap used when the `debug-checks` feature is disabled.
+    pub(crate) struct SlotBitmap;
+
+    impl SlotBitmap {
+        // mirrors the signature of the checked bitmap, which does not track zero-sized types
+        #[allow(clippy::extra_unused_type_parameters)]
+        #[inline(always)]
+        pub(crate) fn new<T>(_: usize, _: usize) -> Self {
+            Self
+        }
+
+        #[inline(always)]
+        pub(crate) fn grow(&mut self, _: usize) {}
+
+        #[inline(always)]
+        pub(crate) fn mark_reserved(&self, _: usize) {}
+
+        #[inline(always)]
+        pub(crate) fn mark_initialized_range(&self, _: Range<usize>) {}
+
+        #[inline(always)]
+        pub(crate) fn mark_written(&self, _: usize) {}
+
+        #[inline(always)]
+        pub(crate) fn assert_uninitialized(&self, _: Range<usize>) {}
+
+        #[inline(always)]
+        pub(crate) fn assert_initialized(&self, _: Range<usize>) {}
+
+        #[inline(always)]
+        pub(crate) fn assert_initialized_or_reserved(&self, _: Range<usize>) {}
+    }
+}
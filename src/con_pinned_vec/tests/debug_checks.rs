@@ -0,0 +1,238 @@
+use crate::prelude::*;
+use crate::{ConcurrentFixedBag, ConcurrentFixedVec};
+use alloc::string::{String, ToString};
+
+fn con_vec_with_written(capacity: usize, written: &[usize]) -> ConcurrentFixedVec<String> {
+    let con_vec = FixedVec::<String>::new(capacity).into_concurrent();
+    for i in written {
+        unsafe { con_vec.write(*i, i.to_string()) };
+    }
+    con_vec
+}
+
+#[test]
+fn valid_usage() {
+    let mut con_vec = con_vec_with_written(8, &[3, 0, 2, 1]);
+    con_vec.fill_with(4..6, || "x".to_string());
+
+    assert_eq!(unsafe { con_vec.get(5) }, Some(&"x".to_string()));
+    assert_eq!(con_vec.slices(0..6).map(|x| x.len()), Some(6));
+    assert_eq!(unsafe { con_vec.iter(6) }.count(), 6);
+    assert_eq!(unsafe { con_vec.iter_over_range(1..4) }.count(), 3);
+    assert_eq!(unsafe { con_vec.get_mut(0) }, Some(&mut "0".to_string()));
+
+    let clone = unsafe { con_vec.clone_with_len(6) };
+    assert_eq!(unsafe { clone.into_inner(6) }.len(), 6);
+
+    let vec = unsafe { con_vec.into_inner(6) };
+    assert_eq!(vec, ["0", "1", "2", "3", "x", "x"].map(String::from));
+}
+
+#[test]
+fn positions_of_the_source_vector_are_initialized() {
+    let mut vec = FixedVec::new(4);
+    vec.extend_from_slice(&["a".to_string(), "b".to_string()]);
+    let con_vec = vec.into_concurrent();
+
+    assert_eq!(unsafe { con_vec.get(1) }, Some(&"b".to_string()));
+    unsafe { con_vec.write(2, "c".to_string()) };
+    assert_eq!(unsafe { con_vec.into_inner(3) }.len(), 3);
+}
+
+#[test]
+fn overwrite_pre_filled_positions() {
+    let mut vec = FixedVec::new(4);
+    vec.push("a".to_string());
+    let con_vec = vec.into_concurrent_filled_with(|| "x".to_string());
+
+    let ptr = unsafe { con_vec.get_ptr_mut(1) };
+    unsafe { *ptr = "b".to_string() };
+    unsafe { *con_vec.get_ptr_mut(1) = "c".to_string() };
+
+    let vec = unsafe { con_vec.into_inner(4) };
+    assert_eq!(vec, ["a", "c", "x", "x"].map(String::from));
+}
+
+#[test]
+fn get_ptr_mut_reserves_and_mark_written_initializes() {
+    let con_vec = FixedVec::<String>::new(4).into_concurrent();
+    unsafe { con_vec.get_ptr_mut(0).write("a".to_string()) };
+    con_vec.mark_written(0);
+    let first = unsafe { con_vec.get_ptr_mut(0) };
+    assert_eq!(
+        unsafe { con_vec.get(0) }.map(|x| x as *const _),
+        Some(first as *const _)
+    );
+    assert_eq!(unsafe { con_vec.into_inner(1) }, ["a".to_string()]);
+}
+
+#[test]
+fn reserved_positions_are_accepted_by_len() {
+    let con_vec = FixedVec::<String>::new(4).into_concurrent();
+    unsafe { con_vec.get_ptr_mut(1).write("b".to_string()) };
+    unsafe { con_vec.get_ptr_mut(0).write("a".to_string()) };
+
+    assert_eq!(unsafe { con_vec.iter(2) }.count(), 2);
+    assert_eq!(
+        unsafe { con_vec.into_inner(2) },
+        ["a", "b"].map(String::from)
+    );
+}
+
+#[test]
+fn reserve_keeps_written_positions() {
+    let mut con_vec = FixedVec::<String>::new(2).into_concurrent();
+    unsafe { con_vec.write(0, "a".to_string()) };
+    let capacity = unsafe { con_vec.reserve_maximum_concurrent_capacity(0, 10) };
+    assert!(capacity >= 10);
+    assert_eq!(unsafe { con_vec.into_inner(1) }, ["a".to_string()]);
+}
+
+#[test]
+fn panic_while_creating_value_leaves_position_uninitialized() {
+    let con_vec = con_vec_with_written(4, &[0]);
+    let create = |fail: bool| {
+        assert!(!fail, "failed to create");
+        "b".to_string()
+    };
+
+    let result = std::panic::catch_unwind(core::panic::AssertUnwindSafe(|| unsafe {
+        con_vec.write(1, create(true))
+    }));
+    assert!(result.is_err());
+
+    unsafe { con_vec.write(1, create(false)) };
+    assert_eq!(
+        unsafe { con_vec.into_inner(2) },
+        ["0", "b"].map(String::from)
+    );
+}
+
+#[test]
+fn clear_and_reserve_reset_positions() {
+    let mut con_vec = con_vec_with_written(4, &[0, 1]);
+    unsafe { con_vec.clear(2) };
+    unsafe { con_vec.write(0, "again".to_string()) };
+    unsafe { con_vec.clear(1) };
+
+    let capacity = unsafe { con_vec.reserve_maximum_concurrent_capacity(0, 10) };
+    con_vec.fill_with(0..capacity, String::new);
+    unsafe { con_vec.clear(capacity) };
+
+    let capacity =
+        unsafe { con_vec.reserve_maximum_concurrent_capacity_fill_with(0, 20, String::new) };
+    assert_eq!(unsafe { con_vec.into_inner(capacity) }.len(), capacity);
+}
+
+#[test]
+fn concurrent_bag() {
+    let bag = ConcurrentFixedBag::new(64);
+    std::thread::scope(|s| {
+        for t in 0..4 {
+            let bag = &bag;
+            s.spawn(move || {
+                for i in 0..16 {
+                    assert!(bag.push(t * 16 + i).is_ok());
+                }
+            });
+        }
+    });
+    assert_eq!(bag.into_inner().len(), 64);
+}
+
+#[test]
+fn zero_sized_positions_are_not_tracked() {
+    let con_vec = FixedVec::<()>::new(usize::MAX).into_concurrent();
+    assert_eq!(unsafe { con_vec.get(42) }, Some(&()));
+    unsafe { con_vec.write(0, ()) };
+    unsafe { con_vec.write(0, ()) };
+}
+
+#[test]
+#[should_panic(expected = "read before being written; slot: 2")]
+fn get_uninitialized() {
+    let con_vec = con_vec_with_written(4, &[0, 1]);
+    let _ = unsafe { con_vec.get(2) };
+}
+
+#[test]
+#[should_panic(expected = "read before being written; slot: 1")]
+fn get_after_get_ptr_mut_without_write() {
+    let con_vec = con_vec_with_written(4, &[0]);
+    let _ = unsafe { con_vec.get_ptr_mut(1) };
+    let _ = unsafe { con_vec.get(1) };
+}
+
+#[test]
+#[should_panic(expected = "written more than once; slot: 1")]
+fn write_after_get_ptr_mut() {
+    let con_vec = con_vec_with_written(4, &[0]);
+    unsafe { con_vec.get_ptr_mut(1).write("b".to_string()) };
+    unsafe { con_vec.write(1, "overwritten".to_string()) };
+}
+
+#[test]
+#[should_panic(expected = "written more than once; slot: 1")]
+fn mark_written_twice() {
+    let con_vec = con_vec_with_written(4, &[0]);
+    unsafe { con_vec.get_ptr_mut(1).write("b".to_string()) };
+    con_vec.mark_written(1);
+    con_vec.mark_written(1);
+}
+
+#[test]
+#[should_panic(expected = "read before being written; slot: 1")]
+fn slices_uninitialized() {
+    let con_vec = con_vec_with_written(4, &[0, 2]);
+    let _ = con_vec.slices(0..3);
+}
+
+#[test]
+#[should_panic(expected = "read before being written; slot: 3")]
+fn iter_uninitialized() {
+    let con_vec = con_vec_with_written(4, &[0, 1, 2]);
+    let _ = unsafe { con_vec.iter(4) }.count();
+}
+
+#[test]
+#[should_panic(expected = "written more than once; slot: 1")]
+fn double_write() {
+    let con_vec = con_vec_with_written(4, &[0, 1]);
+    unsafe { con_vec.write(1, "overwritten".to_string()) };
+}
+
+#[test]
+#[should_panic(expected = "written more than once; slot: 2")]
+fn write_over_pre_filled() {
+    let con_vec = FixedVec::<String>::new(4).into_concurrent_filled_with(String::new);
+    unsafe { con_vec.write(2, "overwritten".to_string()) };
+}
+
+#[test]
+#[should_panic(expected = "written more than once; slot: 2")]
+fn fill_with_over_written() {
+    let con_vec = con_vec_with_written(4, &[2]);
+    con_vec.fill_with(0..4, String::new);
+}
+
+#[test]
+#[should_panic(expected = "read before being written; slot: 2")]
+fn into_inner_with_mismatched_len() {
+    let con_vec = con_vec_with_written(4, &[0, 1]);
+    let _ = unsafe { con_vec.into_inner(3) };
+}
+
+#[test]
+#[should_panic(expected = "read before being written; slot: 1")]
+fn clear_with_mismatched_len() {
+    let mut con_vec = con_vec_with_written(4, &[0]);
+    unsafe { con_vec.clear(2) };
+}
+
+#[test]
+#[should_panic(expected = "read before being written; slot: 0")]
+fn read_after_clear() {
+    let mut con_vec = con_vec_with_written(4, &[0, 1]);
+    unsafe { con_vec.clear(2) };
+    let _ = unsafe { con_vec.get(0) };
+}
@@ -1,11 +1,12 @@
 use crate::con_pinned_vec::into_iter::ConcurrentFixedVecIntoIter;
+use crate::helpers::vec::from_std_vec;
 
 #[test]
 fn into_iter_empty() {
     let iter = || {
         let data: Vec<_> = (0..0).map(|x| x.to_string()).collect();
         let range = 0..data.len();
-        ConcurrentFixedVecIntoIter::new(data, range)
+        ConcurrentFixedVecIntoIter::new(from_std_vec(data), range)
     };
 
     let consume_all = iter().count();
@@ -24,7 +25,7 @@ fn into_iter_non_taken() {
     let iter = || {
         let data: Vec<_> = (0..20).map(|x| x.to_string()).collect();
         let range = 0..data.len();
-        ConcurrentFixedVecIntoIter::new(data, range)
+        ConcurrentFixedVecIntoIter::new(from_std_vec(data), range)
     };
 
     let consume_all = iter().count();
@@ -50,7 +51,7 @@ fn into_iter_taken_from_beg() {
             let _value = unsafe { p.read() };
         }
 
-        ConcurrentFixedVecIntoIter::new(data, range)
+        ConcurrentFixedVecIntoIter::new(from_std_vec(data), range)
     };
 
     let consume_all = iter().count();
@@ -76,7 +77,7 @@ fn into_iter_taken_from_end() {
             let _value = unsafe { p.read() };
         }
 
-        ConcurrentFixedVecIntoIter::new(data, range)
+        ConcurrentFixedVecIntoIter::new(from_std_vec(data), range)
     };
 
     let consume_all = iter().count();
@@ -102,7 +103,7 @@ fn into_iter_taken_from_both_ends() {
             let _value = unsafe { p.read() };
         }
 
-        ConcurrentFixedVecIntoIter::new(data, range)
+        ConcurrentFixedVecIntoIter::new(from_std_vec(data), range)
     };
 
     let consume_all = iter().count();
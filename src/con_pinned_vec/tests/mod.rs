@@ -1 +1,6 @@
+#[cfg(feature = "debug-checks")]
+mod debug_checks;
 mod into_iter;
+mod panic_safety;
+mod provenance;
+mod reserve;
@@ -0,0 +1,138 @@
+use crate::ConcurrentFixedVec;
+use crate::helpers::drop_counter::{DropCounter, Tracked};
+use crate::prelude::*;
+use std::panic::{AssertUnwindSafe, catch_unwind, resume_unwind};
+
+/// Returns a closure creating tracked elements which panics while creating the element `panic_at`.
+fn create_until(counter: &DropCounter, panic_at: usize) -> impl Fn() -> Tracked + use<> {
+    let counter = counter.clone();
+    move || {
+        assert!(counter.created() != panic_at, "fill failed");
+        counter.track()
+    }
+}
+
+#[test]
+#[should_panic(expected = "fill failed")]
+fn fill_with_panic_drops_written_elements() {
+    let counter = DropCounter::new();
+    let con_vec: ConcurrentFixedVec<Tracked> = FixedVec::new(10).into_concurrent();
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        con_vec.fill_with(2..8, create_until(&counter, 3))
+    }));
+    let payload = result.expect_err("must panic");
+
+    assert_eq!(counter.created(), 3);
+    assert_eq!(counter.dropped(), 3);
+
+    // positions are left uninitialized; dropping the empty vector must not drop them again
+    drop(unsafe { con_vec.into_inner(0) });
+    counter.assert_no_leak();
+
+    resume_unwind(payload);
+}
+
+#[test]
+#[should_panic(expected = "fill failed")]
+fn fill_with_panic_keeps_previously_written_elements() {
+    let counter = DropCounter::new();
+    let con_vec: ConcurrentFixedVec<Tracked> = FixedVec::new(10).into_concurrent();
+    con_vec.fill_with(0..4, create_until(&counter, usize::MAX));
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        con_vec.fill_with(4..10, create_until(&counter, 6))
+    }));
+    let payload = result.expect_err("must panic");
+
+    assert_eq!(counter.created(), 6);
+    assert_eq!(counter.dropped(), 2);
+
+    let vec = unsafe { con_vec.into_inner(4) };
+    assert_eq!(vec.len(), 4);
+    drop(vec);
+    counter.assert_no_leak();
+
+    resume_unwind(payload);
+}
+
+#[test]
+#[should_panic(expected = "fill failed")]
+fn reserve_fill_with_panic_drops_written_elements() {
+    let counter = DropCounter::new();
+    let mut con_vec: ConcurrentFixedVec<Tracked> = FixedVec::new(2).into_concurrent();
+
+    let result = catch_unwind(AssertUnwindSafe(|| unsafe {
+        con_vec.reserve_maximum_concurrent_capacity_fill_with(0, 100, create_until(&counter, 40))
+    }));
+    let payload = result.expect_err("must panic");
+
+    assert_eq!(counter.created(), 40);
+    assert_eq!(counter.dropped(), 40);
+
+    // capacity is reserved but the vector is left empty, consistent with the current length of zero
+    assert!(con_vec.capacity() >= 100);
+    let vec = unsafe { con_vec.into_inner(0) };
+    assert!(vec.is_empty());
+    drop(vec);
+    counter.assert_no_leak();
+
+    resume_unwind(payload);
+}
+
+#[test]
+#[should_panic(expected = "fill failed")]
+fn reserve_fill_with_panic_on_first_element() {
+    let counter = DropCounter::new();
+    let mut con_vec: ConcurrentFixedVec<Tracked> = FixedVec::new(0).into_concurrent();
+
+    let result = catch_unwind(AssertUnwindSafe(|| unsafe {
+        con_vec.reserve_maximum_concurrent_capacity_fill_with(0, 10, create_until(&counter, 0))
+    }));
+    let payload = result.expect_err("must panic");
+
+    drop(con_vec);
+    assert_eq!(counter.created(), 0);
+    counter.assert_no_leak();
+
+    resume_unwind(payload);
+}
+
+#[test]
+#[should_panic(expected = "fill failed")]
+fn into_concurrent_filled_with_panic_drops_elements() {
+    let counter = DropCounter::new();
+    let mut vec = FixedVec::new(8);
+    vec.push(create_until(&counter, usize::MAX)());
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        vec.into_concurrent_filled_with(create_until(&counter, 5))
+    }));
+    let payload = result.expect_err("must panic");
+
+    assert_eq!(counter.created(), 5);
+    counter.assert_no_leak();
+
+    resume_unwind(payload);
+}
+
+#[test]
+fn fill_without_panic_does_not_leak() {
+    let counter = DropCounter::new();
+    let mut con_vec: ConcurrentFixedVec<Tracked> = FixedVec::new(3).into_concurrent();
+
+    let capacity = unsafe {
+        con_vec.reserve_maximum_concurrent_capacity_fill_with(
+            0,
+            20,
+            create_until(&counter, usize::MAX),
+        )
+    };
+    assert_eq!(counter.created(), capacity);
+    assert_eq!(counter.dropped(), 0);
+
+    let vec = unsafe { con_vec.into_inner(capacity) };
+    assert_eq!(vec.len(), capacity);
+    drop(vec);
+    counter.assert_no_leak();
+}
@@ -0,0 +1,103 @@
+//! Interleaves raw pointers and references to the elements, which must be accepted
+//! under both Stacked and Tree Borrows when run with `-Zmiri-strict-provenance`.
+
+use crate::prelude::*;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+#[test]
+fn ptr_stays_valid_after_get_mut() {
+    let mut con_vec = FixedVec::<String>::new(4).into_concurrent();
+
+    let p0 = unsafe { con_vec.get_ptr_mut(0) };
+    let p1 = unsafe { con_vec.get_ptr_mut(1) };
+    unsafe { p0.write("a".to_string()) };
+    unsafe { p1.write("b".to_string()) };
+    con_vec.mark_written(0);
+    con_vec.mark_written(1);
+
+    let x = unsafe { con_vec.get_mut(1) }.expect("in-bounds");
+    x.push('!');
+
+    // pointers obtained before the mutable reference are still usable
+    unsafe { (*p0).push('?') };
+    assert_eq!(unsafe { &*p1 }, "b!");
+
+    let vec = unsafe { con_vec.into_inner(2) };
+    assert_eq!(vec, ["a?", "b!"].map(String::from));
+}
+
+#[test]
+fn ptr_stays_valid_after_slices_and_iter_mut() {
+    let mut con_vec = FixedVec::<String>::new(4).into_concurrent_filled_with(String::new);
+    let ptrs: Vec<_> = unsafe { con_vec.ptr_iter_unchecked(0..4) }.collect();
+    let (first, last) = (ptrs[0], ptrs[3]);
+
+    for (i, p) in ptrs.iter().enumerate() {
+        unsafe { (**p).push_str(&i.to_string()) };
+    }
+
+    let slice = con_vec.slices(1..3).expect("in-bounds");
+    assert_eq!(slice, ["1", "2"]);
+
+    if let Some(slice) = unsafe { con_vec.slices_mut(2..4) } {
+        slice[0].push('!');
+    }
+    unsafe { (*last).push('?') };
+
+    for x in unsafe { con_vec.iter_mut(4) } {
+        x.push('.');
+    }
+    unsafe { (*first).push('#') };
+
+    let values: Vec<_> = unsafe { con_vec.iter(4) }.cloned().collect();
+    assert_eq!(values, ["0.#", "1.", "2!.", "3?."]);
+
+    let vec = unsafe { con_vec.into_inner(4) };
+    assert_eq!(vec, values);
+}
+
+#[test]
+fn clone_with_len_keeps_ptr_valid() {
+    let con_vec = FixedVec::<String>::new(3).into_concurrent();
+    let p = unsafe { con_vec.get_ptr_mut(0) };
+    unsafe { p.write("x".to_string()) };
+
+    let clone = unsafe { con_vec.clone_with_len(1) };
+    unsafe { (*p).push('y') };
+
+    let vec = unsafe { con_vec.into_inner(1) };
+    let clone = unsafe { clone.into_inner(1) };
+    assert_eq!(vec, ["xy"].map(String::from));
+    assert_eq!(clone, ["x"].map(String::from));
+}
+
+#[test]
+fn into_iter_after_pointer_writes() {
+    let con_vec = FixedVec::<String>::new(5).into_concurrent();
+    for i in 0..5 {
+        unsafe { con_vec.get_ptr_mut(i).write(i.to_string()) };
+    }
+    let _ = con_vec.slices(0..5);
+
+    let mut iter = unsafe { con_vec.into_iter(0..5) };
+    assert_eq!(iter.next().as_deref(), Some("0"));
+    assert_eq!(iter.next().as_deref(), Some("1"));
+    // remaining elements are dropped with the iterator
+}
+
+#[test]
+fn drop_drops_elements_of_the_source_vec() {
+    let mut vec = FixedVec::new(4);
+    vec.push("a".to_string());
+    vec.push("b".to_string());
+
+    let con_vec = vec.into_concurrent();
+    // written beyond len; not owned by the concurrent vector
+    let extra = unsafe { con_vec.get_ptr_mut(2) };
+    unsafe { extra.write("c".to_string()) };
+    let extra = unsafe { extra.read() };
+
+    drop(con_vec);
+    assert_eq!(extra, "c");
+}
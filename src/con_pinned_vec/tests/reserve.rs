@@ -0,0 +1,137 @@
+use crate::ConcurrentFixedVec;
+use crate::helpers::drop_counter::DropCounter;
+use crate::prelude::*;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+/// Asserts that all pointer based accessors of the concurrent vector point to the same buffer.
+fn assert_consistent_pointers(con_vec: &ConcurrentFixedVec<String>) {
+    let capacity = con_vec.capacity();
+    let slice = con_vec.slices(0..capacity).expect("in-bounds");
+    assert_eq!(slice.len(), capacity);
+
+    let ptrs: Vec<_> = unsafe { con_vec.ptr_iter_unchecked(0..capacity) }.collect();
+    for i in 0..capacity {
+        let ptr = unsafe { con_vec.get_ptr_mut(i) };
+        assert_eq!(ptr as *const String, &slice[i] as *const String);
+        assert_eq!(ptr, ptrs[i]);
+        assert_eq!(
+            Some(ptr as *const String),
+            unsafe { con_vec.get(i) }.map(|x| x as *const _)
+        );
+    }
+}
+
+#[test]
+fn reserve_empty_refreshes_pointers() {
+    let mut con_vec = FixedVec::<String>::new(4).into_concurrent_filled_with(String::new);
+    unsafe { con_vec.clear(4) };
+
+    let capacity = unsafe { con_vec.reserve_maximum_concurrent_capacity(0, 1000) };
+    assert!(capacity >= 1000);
+    assert_eq!(con_vec.capacity(), capacity);
+
+    for i in 0..capacity {
+        unsafe { con_vec.get_ptr_mut(i).write(i.to_string()) };
+        con_vec.mark_written(i);
+    }
+    assert_consistent_pointers(&con_vec);
+
+    let first = unsafe { con_vec.get_ptr_mut(0) } as *const String;
+    let vec = unsafe { con_vec.into_inner(capacity) };
+    assert_eq!(vec.as_ptr(), first);
+    assert_eq!(
+        vec,
+        (0..capacity).map(|i| i.to_string()).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn reserve_fill_with_empty_refreshes_pointers() {
+    let mut con_vec = FixedVec::<String>::new(4).into_concurrent();
+
+    let capacity = unsafe {
+        con_vec.reserve_maximum_concurrent_capacity_fill_with(0, 1000, || "x".to_string())
+    };
+    assert!(capacity >= 1000);
+    assert_consistent_pointers(&con_vec);
+
+    let iter_len = unsafe { con_vec.iter(capacity) }
+        .filter(|x| x.as_str() == "x")
+        .count();
+    assert_eq!(iter_len, capacity);
+
+    let vec = unsafe { con_vec.into_inner(capacity) };
+    assert_eq!(vec.len(), capacity);
+}
+
+#[test]
+fn reserve_non_empty_keeps_elements_pinned() {
+    let mut con_vec = FixedVec::<String>::new(4).into_concurrent();
+    for i in 0..3 {
+        unsafe { con_vec.get_ptr_mut(i).write(i.to_string()) };
+    }
+    let ptrs_before: Vec<_> = unsafe { con_vec.ptr_iter_unchecked(0..4) }.collect();
+
+    let capacity = unsafe { con_vec.reserve_maximum_concurrent_capacity(3, 1000) };
+    assert_eq!(capacity, 4);
+    assert_eq!(con_vec.capacity(), 4);
+    assert_eq!(con_vec.max_capacity(), 4);
+
+    let ptrs_after: Vec<_> = unsafe { con_vec.ptr_iter_unchecked(0..4) }.collect();
+    assert_eq!(ptrs_before, ptrs_after);
+
+    let vec = unsafe { con_vec.into_inner(3) };
+    assert_eq!(vec, ["0", "1", "2"].map(String::from));
+    assert_eq!(vec.as_ptr() as *mut String, ptrs_before[0]);
+}
+
+#[test]
+fn reserve_within_capacity_is_no_op() {
+    let mut con_vec = FixedVec::<String>::new(8).into_concurrent();
+    let ptrs_before: Vec<_> = unsafe { con_vec.ptr_iter_unchecked(0..8) }.collect();
+
+    assert_eq!(
+        unsafe { con_vec.reserve_maximum_concurrent_capacity(0, 5) },
+        8
+    );
+    assert_eq!(
+        unsafe { con_vec.reserve_maximum_concurrent_capacity_fill_with(0, 8, String::new) },
+        8
+    );
+
+    let ptrs_after: Vec<_> = unsafe { con_vec.ptr_iter_unchecked(0..8) }.collect();
+    assert_eq!(ptrs_before, ptrs_after);
+
+    let vec = unsafe { con_vec.into_inner(0) };
+    assert!(vec.is_empty());
+}
+
+#[test]
+fn reserve_empty_drops_each_element_once() {
+    let drops = DropCounter::new();
+    let mut con_vec = FixedVec::new(4).into_concurrent_filled_with(|| drops.track());
+    unsafe { con_vec.clear(4) };
+    assert_eq!(drops.dropped(), 4);
+
+    let capacity =
+        unsafe { con_vec.reserve_maximum_concurrent_capacity_fill_with(0, 100, || drops.track()) };
+    assert_eq!(drops.created(), 4 + capacity);
+    assert_eq!(drops.dropped(), 4);
+
+    unsafe { con_vec.clear(capacity) };
+    let capacity = unsafe { con_vec.reserve_maximum_concurrent_capacity(0, 1000) };
+    assert!(capacity >= 1000);
+
+    drop(con_vec);
+    drops.assert_no_leak();
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "can only be reallocated when it is empty")]
+fn reserve_with_leftover_elements() {
+    let drops = DropCounter::new();
+    let mut con_vec = FixedVec::new(4).into_concurrent_filled_with(|| drops.track());
+    let _ = unsafe { con_vec.reserve_maximum_concurrent_capacity(0, 100) };
+}
@@ -1,4 +1,5 @@
 use crate::FixedVec;
+use allocator_api2::alloc::Allocator;
 use orx_concurrent_iter::{
     IntoConcurrentIter,
     implementations::{ConIterSlice, ConIterVec},
@@ -13,19 +14,20 @@ where
     type IntoIter = ConIterVec<T>;
 
     fn into_con_iter(self) -> Self::IntoIter {
-        self.data.into_con_iter()
+        self.into_inner().into_con_iter()
     }
 }
 
-impl<'a, T> IntoConcurrentIter for &'a FixedVec<T>
+impl<'a, T, A> IntoConcurrentIter for &'a FixedVec<T, A>
 where
     T: Sync,
+    A: Allocator,
 {
     type Item = &'a T;
 
     type IntoIter = ConIterSlice<'a, T>;
 
     fn into_con_iter(self) -> Self::IntoIter {
-        self.data.into_con_iter()
+        self.data.as_slice().into_con_iter()
     }
 }
@@ -0,0 +1,127 @@
+use core::fmt::{Debug, Display};
+
+/// Error returned by the fallible growth methods of the `FixedVec`, such as
+/// [`try_push`], [`try_insert`], [`try_extend_from_slice`] and [`try_extend`],
+/// when the vector does not have enough room for the new elements.
+///
+/// The error hands back the rejected value(s) which can be obtained by [`into_rejected`],
+/// and it reports the capacity and length of the vector at the moment of failure.
+///
+/// [`try_push`]: crate::FixedVec::try_push
+/// [`try_insert`]: crate::FixedVec::try_insert
+/// [`try_extend_from_slice`]: crate::FixedVec::try_extend_from_slice
+/// [`try_extend`]: crate::FixedVec::try_extend
+/// [`into_rejected`]: FixedVecFullError::into_rejected
+///
+/// # Examples
+///
+/// ```
+/// use orx_fixed_vec::prelude::*;
+///
+/// let mut vec = FixedVec::new(1);
+/// assert!(vec.try_push('a').is_ok());
+///
+/// let error = vec.try_push('b').unwrap_err();
+/// assert_eq!(error.capacity(), 1);
+/// assert_eq!(error.len(), 1);
+/// assert_eq!(error.rejected(), &'b');
+/// assert_eq!(error.into_rejected(), 'b');
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct FixedVecFullError<R> {
+    rejected: R,
+    capacity: usize,
+    len: usize,
+}
+
+impl<R> FixedVecFullError<R> {
+    pub(crate) fn new(rejected: R, capacity: usize, len: usize) -> Self {
+        Self {
+            rejected,
+            capacity,
+            len,
+        }
+    }
+
+    /// Returns a reference to the rejected value(s) which could not be added to the vector.
+    pub fn rejected(&self) -> &R {
+        &self.rejected
+    }
+
+    /// Consumes the error and returns back the rejected value(s) which could not be added to the vector.
+    pub fn into_rejected(self) -> R {
+        self.rejected
+    }
+
+    /// Fixed capacity of the vector at the moment of failure.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Length of the vector at the moment of failure.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the vector was empty at the moment of failure,
+    /// which can only happen when its capacity is zero.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Available room of the vector at the moment of failure; i.e., `capacity() - len()`.
+    pub fn room(&self) -> usize {
+        self.capacity - self.len
+    }
+}
+
+impl<R> Debug for FixedVecFullError<R> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FixedVecFullError")
+            .field("capacity", &self.capacity)
+            .field("len", &self.len)
+            .finish()
+    }
+}
+
+impl<R> Display for FixedVecFullError<R> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "FixedVec with fixed capacity {} and length {} does not have enough room for the new element(s).",
+            self.capacity, self.len
+        )
+    }
+}
+
+impl<R> core::error::Error for FixedVecFullError<R> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn full_error() {
+        let error = FixedVecFullError::new(42, 4, 3);
+        assert_eq!(error.rejected(), &42);
+        assert_eq!(error.capacity(), 4);
+        assert_eq!(error.len(), 3);
+        assert_eq!(error.room(), 1);
+        assert!(!error.is_empty());
+        assert_eq!(error.into_rejected(), 42);
+    }
+
+    #[test]
+    fn full_error_debug_display() {
+        let error = FixedVecFullError::new('x', 2, 2);
+        assert_eq!(
+            format!("{:?}", error),
+            "FixedVecFullError { capacity: 2, len: 2 }"
+        );
+        assert_eq!(
+            format!("{}", error),
+            "FixedVec with fixed capacity 2 and length 2 does not have enough room for the new element(s)."
+        );
+    }
+}
@@ -0,0 +1,339 @@
+use crate::FixedVecFullError;
+use alloc::boxed::Box;
+use core::mem::{ManuallyDrop, MaybeUninit};
+use core::ptr::NonNull;
+
+/// A fixed array vector, `FixedArrayVec<T, N>`, is a vector with a strict compile-time capacity of `N`
+/// whose elements are stored in a `[MaybeUninit<T>; N]` array rather than in a buffer whose capacity is
+/// decided at runtime as in [`FixedVec`](crate::FixedVec).
+///
+/// # Pinned Elements
+///
+/// Moving an array moves its elements. Therefore, the vector owns its array through a `Box` which is
+/// directly allocated on the heap by [`FixedArrayVec::new`], and it never exposes the array itself.
+/// Moving or swapping vectors, such as by `core::mem::swap`, moves only the pointers to the arrays;
+/// and hence, memory locations of the elements never change.
+///
+/// Apart from its inline storage, it mirrors the `FixedVec` api:
+///
+/// * it can never grow beyond its capacity `N`; and
+/// * adding elements to a full vector panics, while [`try_push`](FixedArrayVec::try_push) returns an error instead.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_fixed_vec::prelude::*;
+///
+/// let mut vec: FixedArrayVec<usize, 4> = FixedArrayVec::new();
+/// assert_eq!(4, vec.capacity());
+///
+/// vec.push(42);
+/// let addr42 = &vec[0] as *const usize;
+///
+/// vec.extend_from_slice(&[1, 2, 3]);
+/// assert!(vec.is_full());
+/// assert_eq!(vec.as_slice(), &[42, 1, 2, 3]);
+///
+/// // moving the vector does not move the elements
+/// let moved = vec;
+/// assert_eq!(addr42, &moved[0] as *const usize);
+/// ```
+pub struct FixedArrayVec<T, const N: usize> {
+    pub(super) len: usize,
+    data: Box<[MaybeUninit<T>; N]>,
+}
+
+impl<T, const N: usize> FixedArrayVec<T, N> {
+    /// Creates a new empty fixed array vector with the fixed capacity of `N`,
+    /// whose array is directly allocated on the heap.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_fixed_vec::prelude::*;
+    ///
+    /// let vec = FixedArrayVec::<char, 7>::new();
+    ///
+    /// assert_eq!(7, vec.capacity());
+    /// assert!(vec.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        // SAFETY: an array of `MaybeUninit` does not require initialization
+        let data = unsafe { Box::<[MaybeUninit<T>; N]>::new_uninit().assume_init() };
+        Self { len: 0, data }
+    }
+
+    /// Returns the fixed capacity of the vector, which is equal to `N`.
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns the available room for new items; i.e.,
+    /// `capacity() - len()`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_fixed_vec::prelude::*;
+    ///
+    /// let mut vec = FixedArrayVec::<_, 7>::new();
+    /// vec.push(42);
+    ///
+    /// assert_eq!(7, vec.capacity());
+    /// assert_eq!(1, vec.len());
+    /// assert_eq!(6, vec.room());
+    /// ```
+    pub fn room(&self) -> usize {
+        N - self.len
+    }
+
+    /// Return whether the fixed array vector is full or not;
+    /// equivalent to `capacity() == len()` or `room() == 0`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_fixed_vec::prelude::*;
+    ///
+    /// let mut vec = FixedArrayVec::<_, 2>::new();
+    /// assert!(!vec.is_full());
+    ///
+    /// vec.push(42);
+    /// assert!(!vec.is_full());
+    ///
+    /// vec.push(7);
+    /// assert!(vec.is_full());
+    /// ```
+    pub fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Extracts a slice containing the entire vector.
+    pub fn as_slice(&self) -> &[T] {
+        // SAFETY: elements within 0..len are initialized
+        unsafe { core::slice::from_raw_parts(self.ptr(), self.len) }
+    }
+
+    /// Extracts a mutable slice containing the entire vector.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        // SAFETY: elements within 0..len are initialized
+        unsafe { core::slice::from_raw_parts_mut(self.ptr_mut(), self.len) }
+    }
+
+    /// Appends an element to the back of the vector if there is available room;
+    /// returns back the `value` in a [`FixedVecFullError`] otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_fixed_vec::prelude::*;
+    ///
+    /// let mut vec = FixedArrayVec::<_, 1>::new();
+    ///
+    /// assert!(vec.try_push('a').is_ok());
+    ///
+    /// let error = vec.try_push('b').unwrap_err();
+    /// assert_eq!(error.into_rejected(), 'b');
+    /// ```
+    pub fn try_push(&mut self, value: T) -> Result<(), FixedVecFullError<T>> {
+        match self.is_full() {
+            true => Err(FixedVecFullError::new(value, N, self.len)),
+            false => {
+                self.data[self.len].write(value);
+                self.len += 1;
+                Ok(())
+            }
+        }
+    }
+
+    // helpers
+
+    /// Leaks the array of the vector and returns the pointer to its first position together with the length.
+    pub(super) fn into_raw_parts(self) -> (NonNull<T>, usize) {
+        let me = ManuallyDrop::new(self);
+        // SAFETY: the vector is not dropped; hence, data is moved out exactly once
+        let data = unsafe { core::ptr::read(&me.data) };
+        (NonNull::from(Box::leak(data)).cast(), me.len)
+    }
+
+    /// Creates the vector back from the parts obtained by `into_raw_parts`.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be obtained by `into_raw_parts` of a vector with the same `N`, and ownership of the array is
+    /// taken back only once. Exactly the elements within `0..len` must be initialized.
+    pub(super) unsafe fn from_raw_parts(ptr: NonNull<T>, len: usize) -> Self {
+        debug_assert!(len <= N);
+        // SAFETY: ptr is created by leaking a box of an array of the same type
+        let data = unsafe { Box::from_raw(ptr.cast::<[MaybeUninit<T>; N]>().as_ptr()) };
+        Self { len, data }
+    }
+
+    #[inline(always)]
+    pub(crate) fn ptr(&self) -> *const T {
+        self.data.as_ptr() as *const T
+    }
+
+    #[inline(always)]
+    pub(crate) fn ptr_mut(&mut self) -> *mut T {
+        self.data.as_mut_ptr() as *mut T
+    }
+
+    #[inline(always)]
+    pub(crate) fn panic_if_not_enough_room_for(&self, num_new_items: usize) {
+        assert!(self.len + num_new_items <= N, "{}", ERR_MSG_OUT_OF_ROOM);
+    }
+
+    #[inline(always)]
+    pub(crate) fn push_or_panic(&mut self, value: T) {
+        assert!(self.len < N, "{}", ERR_MSG_OUT_OF_ROOM);
+        self.data[self.len].write(value);
+        self.len += 1;
+    }
+
+    pub(crate) fn insert_or_panic(&mut self, index: usize, element: T) {
+        assert!(index <= self.len, "insertion index is out of bounds");
+        self.panic_if_not_enough_room_for(1);
+        let p = unsafe { self.ptr_mut().add(index) };
+        // SAFETY: index <= len < N; hence, shifting `len - index` elements to the right stays in bounds
+        unsafe { core::ptr::copy(p, p.add(1), self.len - index) };
+        unsafe { p.write(element) };
+        self.len += 1;
+    }
+
+    pub(crate) fn remove_or_panic(&mut self, index: usize) -> T {
+        assert!(index < self.len, "removal index is out of bounds");
+        let p = unsafe { self.ptr_mut().add(index) };
+        // SAFETY: index < len; value is moved out and the gap is closed by shifting the tail to the left
+        let value = unsafe { p.read() };
+        unsafe { core::ptr::copy(p.add(1), p, self.len - index - 1) };
+        self.len -= 1;
+        value
+    }
+
+    pub(crate) fn pop_back(&mut self) -> Option<T> {
+        match self.len {
+            0 => None,
+            _ => {
+                self.len -= 1;
+                // SAFETY: the element at the previous last position is initialized and is now moved out
+                Some(unsafe { self.data[self.len].assume_init_read() })
+            }
+        }
+    }
+
+    pub(crate) fn truncate_to(&mut self, len: usize) {
+        if len < self.len {
+            let tail_len = self.len - len;
+            self.len = len;
+            let tail =
+                unsafe { core::slice::from_raw_parts_mut(self.ptr_mut().add(len), tail_len) };
+            // SAFETY: elements in the tail are initialized and no longer belong to the vector
+            unsafe { core::ptr::drop_in_place(tail) };
+        }
+    }
+}
+
+impl<T, const N: usize> Drop for FixedArrayVec<T, N> {
+    fn drop(&mut self) {
+        self.truncate_to(0);
+    }
+}
+
+const ERR_MSG_OUT_OF_ROOM: &str =
+    "FixedArrayVec is full, a fixed capacity vector cannot exceed its capacity.";
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use alloc::string::{String, ToString};
+
+    #[test]
+    fn new() {
+        let vec = FixedArrayVec::<char, 17>::new();
+        assert_eq!(0, vec.len());
+        assert!(vec.is_empty());
+        assert_eq!(17, vec.capacity());
+
+        let vec = FixedArrayVec::<char, 0>::new();
+        assert_eq!(0, vec.capacity());
+        assert!(vec.is_full());
+    }
+
+    #[test]
+    fn room() {
+        let mut vec = FixedArrayVec::<_, 10>::new();
+
+        for i in 0..vec.capacity() {
+            assert_eq!(i, vec.len());
+            assert_eq!(vec.capacity() - i, vec.room());
+            vec.push(1.1);
+        }
+
+        assert_eq!(vec.len(), vec.capacity());
+        assert_eq!(0, vec.room());
+        assert!(vec.is_full());
+    }
+
+    #[test]
+    fn try_push() {
+        let mut vec = FixedArrayVec::<_, 3>::new();
+
+        for i in 0..3 {
+            assert!(vec.try_push(i.to_string()).is_ok());
+        }
+
+        let error = vec.try_push(3.to_string()).expect_err("is-full");
+        assert_eq!(error.capacity(), 3);
+        assert_eq!(error.len(), 3);
+        assert_eq!(error.into_rejected(), 3.to_string());
+
+        assert_eq!(vec.as_slice(), &["0", "1", "2"]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn push_when_full() {
+        let mut vec = FixedArrayVec::<_, 2>::new();
+        vec.push(0);
+        vec.push(1);
+        vec.push(2);
+    }
+
+    #[test]
+    fn insert_remove_drop() {
+        let mut vec = FixedArrayVec::<String, 8>::new();
+        for i in 0..4 {
+            vec.push(i.to_string());
+        }
+        vec.insert(0, "a".to_string());
+        vec.insert(5, "b".to_string());
+        vec.insert(3, "c".to_string());
+        assert_eq!(vec.as_slice(), &["a", "0", "1", "c", "2", "3", "b"]);
+
+        assert_eq!(vec.remove(3), "c");
+        assert_eq!(vec.remove(0), "a");
+        assert_eq!(vec.remove(4), "b");
+        assert_eq!(vec.as_slice(), &["0", "1", "2", "3"]);
+
+        vec.truncate(1);
+        assert_eq!(vec.as_slice(), &["0"]);
+
+        vec.push("x".to_string());
+    }
+
+    #[test]
+    fn swap_keeps_elements_pinned() {
+        let mut a = FixedArrayVec::<String, 4>::new();
+        let mut b = FixedArrayVec::<String, 4>::new();
+        a.push("a".to_string());
+        b.push("b".to_string());
+        let (addr_a, addr_b) = (&a[0] as *const String, &b[0] as *const String);
+
+        core::mem::swap(&mut a, &mut b);
+        assert_eq!(&b[0] as *const String, addr_a);
+        assert_eq!(&a[0] as *const String, addr_b);
+        assert_eq!(a.as_slice(), &["b"]);
+        assert_eq!(b.as_slice(), &["a"]);
+    }
+}
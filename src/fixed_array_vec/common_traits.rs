@@ -0,0 +1,201 @@
+use super::FixedArrayVec;
+use core::fmt::Debug;
+use core::ops::{Deref, Index, IndexMut};
+use core::slice::SliceIndex;
+
+// as-ref
+
+impl<T, const N: usize> AsRef<[T]> for FixedArrayVec<T, N> {
+    fn as_ref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T, const N: usize> AsMut<[T]> for FixedArrayVec<T, N> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T, const N: usize> Deref for FixedArrayVec<T, N> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+// clone
+
+impl<T, const N: usize> Clone for FixedArrayVec<T, N>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        let mut clone = FixedArrayVec::new();
+        for x in self.as_slice() {
+            clone.push_or_panic(x.clone());
+        }
+        clone
+    }
+}
+
+// debug
+
+impl<T, const N: usize> Debug for FixedArrayVec<T, N>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FixedArrayVec")
+            .field("data", &self.as_slice())
+            .finish()
+    }
+}
+
+// default
+
+impl<T, const N: usize> Default for FixedArrayVec<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// eq
+
+impl<T, U, const N: usize> PartialEq<U> for FixedArrayVec<T, N>
+where
+    U: AsRef<[T]>,
+    T: PartialEq,
+{
+    fn eq(&self, other: &U) -> bool {
+        self.as_slice() == other.as_ref()
+    }
+}
+
+// from-iter
+
+impl<T, const N: usize> FromIterator<T> for FixedArrayVec<T, N> {
+    /// Creates a fixed array vector from the elements of the iterator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the iterator yields more than `N` elements.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut vec = FixedArrayVec::new();
+        for x in iter {
+            vec.push_or_panic(x);
+        }
+        vec
+    }
+}
+
+// index
+
+impl<T, I, const N: usize> Index<I> for FixedArrayVec<T, N>
+where
+    I: SliceIndex<[T]>,
+{
+    type Output = I::Output;
+
+    fn index(&self, index: I) -> &Self::Output {
+        &self.as_slice()[index]
+    }
+}
+
+impl<T, I, const N: usize> IndexMut<I> for FixedArrayVec<T, N>
+where
+    I: SliceIndex<[T]>,
+{
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        &mut self.as_mut_slice()[index]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use alloc::format;
+    use alloc::string::ToString;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn as_ref_as_mut_deref() {
+        let mut vec = FixedArrayVec::<_, 4>::new();
+        for i in 0..4 {
+            vec.push(i);
+        }
+
+        assert_eq!(vec.as_ref(), &[0, 1, 2, 3]);
+
+        for x in vec.as_mut() {
+            *x *= 10;
+        }
+        assert_eq!(&vec[..], &[0, 10, 20, 30]);
+        assert_eq!(vec.iter().sum::<i32>(), 60);
+    }
+
+    #[test]
+    fn clone() {
+        let mut vec = FixedArrayVec::<_, 53>::new();
+        for i in 0..42 {
+            vec.push(i.to_string());
+        }
+
+        let clone = vec.clone();
+        assert_eq!(clone.as_slice(), vec.as_slice());
+        assert_eq!(53, clone.capacity());
+    }
+
+    #[test]
+    fn debug() {
+        let mut vec = FixedArrayVec::<_, 8>::new();
+        for i in 0..4 {
+            vec.push(i);
+        }
+
+        let debug_str = format!("{:?}", vec);
+        assert_eq!("FixedArrayVec { data: [0, 1, 2, 3] }", debug_str);
+    }
+
+    #[test]
+    fn eq() {
+        let mut vec = FixedArrayVec::<_, 42>::new();
+        for i in 0..42 {
+            vec.push(i);
+        }
+
+        let slice = &(0..42).collect::<Vec<_>>();
+        assert_eq!(vec, slice);
+        assert_eq!(vec, vec.clone());
+    }
+
+    #[test]
+    fn from_iter() {
+        let vec: FixedArrayVec<_, 32> = (0..20).collect();
+        assert_eq!(vec.as_slice(), (0..20).collect::<Vec<_>>().as_slice());
+        assert_eq!(32, vec.capacity());
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_iter_exceeding_capacity() {
+        let _vec: FixedArrayVec<_, 8> = (0..20).collect();
+    }
+
+    #[test]
+    fn index() {
+        let mut vec = FixedArrayVec::<_, 42>::new();
+        for i in 0..21 {
+            vec.push(i);
+        }
+
+        for i in 0..vec.len() {
+            assert_eq!(i, vec[i]);
+        }
+
+        vec[7] = 77;
+        assert_eq!(77, vec[7]);
+        assert_eq!(&vec[6..8], &[6, 77]);
+    }
+}
@@ -0,0 +1,419 @@
+use super::{FixedArrayVec, FixedArrayVecIntoIter};
+use crate::{
+    common_traits::ptr_iter::FixedVecPtrIter,
+    helpers::{
+        fill::fill_range_with,
+        range::{range_end, range_start},
+    },
+};
+use core::fmt::Debug;
+use core::mem::ManuallyDrop;
+use core::ptr::NonNull;
+use core::{cmp::Ordering, ops::Range};
+use orx_pinned_vec::{ConcurrentPinnedVec, IntoConcurrentPinnedVec, PinnedVecGrowthError};
+
+/// Concurrent wrapper ([`orx_pinned_vec::ConcurrentPinnedVec`]) for the `FixedArrayVec<T, N>`.
+///
+/// # Thread safety
+///
+/// `ConcurrentFixedArrayVec<T, N>` is `Send` if `T: Send`, and it is `Sync` if `T: Send + Sync`.
+/// It follows the same contract on concurrent method calls as the [`ConcurrentFixedVec`](crate::ConcurrentFixedVec).
+pub struct ConcurrentFixedArrayVec<T, const N: usize> {
+    // leaked array of the vector; converted back to the vector on drop or when converted into inner vector
+    ptr: NonNull<T>,
+    len: usize,
+}
+
+// SAFETY: the wrapper owns the array of the vector.
+unsafe impl<T: Send, const N: usize> Send for ConcurrentFixedArrayVec<T, N> {}
+
+// SAFETY: through a shared reference, elements might be written (T: Send) and referenced (T: Sync).
+unsafe impl<T: Send + Sync, const N: usize> Sync for ConcurrentFixedArrayVec<T, N> {}
+
+impl<T, const N: usize> Debug for ConcurrentFixedArrayVec<T, N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ConcurrentFixedArrayVec")
+            .field("fixed_capacity", &N)
+            .finish()
+    }
+}
+
+impl<T, const N: usize> From<FixedArrayVec<T, N>> for ConcurrentFixedArrayVec<T, N> {
+    fn from(value: FixedArrayVec<T, N>) -> Self {
+        let (ptr, len) = value.into_raw_parts();
+        Self { ptr, len }
+    }
+}
+
+impl<T, const N: usize> Drop for ConcurrentFixedArrayVec<T, N> {
+    fn drop(&mut self) {
+        // SAFETY: ptr is leaked by the vector; the vector drops the elements within 0..len
+        // which is set by the `set_pinned_vec_len` or `clear` methods
+        let _vec = unsafe { FixedArrayVec::<T, N>::from_raw_parts(self.ptr, self.len) };
+    }
+}
+
+impl<T, const N: usize> ConcurrentFixedArrayVec<T, N> {
+    #[inline(always)]
+    fn ptr(&self) -> *mut T {
+        self.ptr.as_ptr()
+    }
+
+    #[inline(always)]
+    fn set_len(&mut self, len: usize) {
+        debug_assert!(len <= N);
+        self.len = len;
+    }
+
+    fn into_vec(self, len: usize) -> FixedArrayVec<T, N> {
+        let me = ManuallyDrop::new(self);
+        // SAFETY: ptr is leaked by the vector and ownership is taken back only once
+        unsafe { FixedArrayVec::from_raw_parts(me.ptr, len) }
+    }
+}
+
+impl<T, const N: usize> ConcurrentPinnedVec<T> for ConcurrentFixedArrayVec<T, N> {
+    type P = FixedArrayVec<T, N>;
+
+    type SliceIter<'a>
+        = Option<&'a [T]>
+    where
+        T: 'a,
+        Self: 'a;
+
+    type SliceMutIter<'a>
+        = Option<&'a mut [T]>
+    where
+        T: 'a,
+        Self: 'a;
+
+    type PtrIter<'a>
+        = FixedVecPtrIter<T>
+    where
+        Self: 'a;
+
+    type IntoIter = FixedArrayVecIntoIter<T, N>;
+
+    unsafe fn into_inner(self, len: usize) -> Self::P {
+        self.into_vec(len)
+    }
+
+    unsafe fn clone_with_len(&self, len: usize) -> Self
+    where
+        T: Clone,
+    {
+        assert!(len <= N);
+        let mut clone = FixedArrayVec::<T, N>::new();
+        for i in 0..len {
+            let value = unsafe { &*self.ptr().add(i) };
+            clone.push_or_panic(value.clone());
+        }
+        clone.into()
+    }
+
+    fn capacity(&self) -> usize {
+        N
+    }
+
+    fn max_capacity(&self) -> usize {
+        N
+    }
+
+    fn grow_to(&self, new_capacity: usize) -> Result<usize, PinnedVecGrowthError> {
+        match new_capacity <= N {
+            true => Ok(N),
+            false => Err(PinnedVecGrowthError::FailedToGrowWhileKeepingElementsPinned),
+        }
+    }
+
+    fn grow_to_and_fill_with<F>(
+        &self,
+        new_capacity: usize,
+        _: F,
+    ) -> Result<usize, PinnedVecGrowthError>
+    where
+        F: Fn() -> T,
+    {
+        match new_capacity <= N {
+            true => Ok(N),
+            false => Err(PinnedVecGrowthError::FailedToGrowWhileKeepingElementsPinned),
+        }
+    }
+
+    fn fill_with<F>(&self, range: Range<usize>, fill_with: F)
+    where
+        F: Fn() -> T,
+    {
+        // SAFETY: positions within `range` are uninitialized and are not accessed by other threads;
+        // elements written so far are dropped if `fill_with` panics
+        unsafe { fill_range_with(self.ptr(), N, range, fill_with) };
+    }
+
+    fn slices<R: core::ops::RangeBounds<usize>>(&self, range: R) -> Option<&[T]> {
+        let a = range_start(&range);
+        let b = range_end(&range, N);
+
+        match b.saturating_sub(a) {
+            0 => Some(&[]),
+            _ => match (a.cmp(&N), b.cmp(&N)) {
+                (Ordering::Equal | Ordering::Greater, _) => None,
+                (_, Ordering::Greater) => None,
+                _ => {
+                    let p = unsafe { self.ptr().add(a) };
+                    let slice = unsafe { core::slice::from_raw_parts(p, b - a) };
+                    Some(slice)
+                }
+            },
+        }
+    }
+
+    unsafe fn slices_mut<R: core::ops::RangeBounds<usize>>(&self, range: R) -> Option<&mut [T]> {
+        let a = range_start(&range);
+        let b = range_end(&range, N);
+
+        match b.saturating_sub(a) {
+            0 => Some(&mut []),
+            _ => match (a.cmp(&N), b.cmp(&N)) {
+                (Ordering::Equal | Ordering::Greater, _) => None,
+                (_, Ordering::Greater) => None,
+                _ => {
+                    let p = unsafe { self.ptr().add(a) };
+                    let slice = unsafe { core::slice::from_raw_parts_mut(p, b - a) };
+                    Some(slice)
+                }
+            },
+        }
+    }
+
+    unsafe fn iter<'a>(&'a self, len: usize) -> impl Iterator<Item = &'a T> + 'a
+    where
+        T: 'a,
+    {
+        let slice = unsafe { core::slice::from_raw_parts(self.ptr(), len) };
+        slice.iter()
+    }
+
+    unsafe fn iter_over_range<'a, R: core::ops::RangeBounds<usize>>(
+        &'a self,
+        range: R,
+    ) -> impl Iterator<Item = &'a T> + 'a
+    where
+        T: 'a,
+    {
+        let [a, b] = orx_pinned_vec::utils::slice::vec_range_limits(&range, None);
+        let p = unsafe { self.ptr().add(a) };
+        let slice = unsafe { core::slice::from_raw_parts(p, b - a) };
+        slice.iter()
+    }
+
+    unsafe fn iter_mut<'a>(&'a mut self, len: usize) -> impl Iterator<Item = &'a mut T> + 'a
+    where
+        T: 'a,
+    {
+        let slice = unsafe { core::slice::from_raw_parts_mut(self.ptr(), len) };
+        slice.iter_mut()
+    }
+
+    unsafe fn set_pinned_vec_len(&mut self, len: usize) {
+        self.set_len(len);
+    }
+
+    unsafe fn get(&self, index: usize) -> Option<&T> {
+        match index < N {
+            true => Some(unsafe { &*self.ptr().add(index) }),
+            false => None,
+        }
+    }
+
+    unsafe fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        match index < N {
+            true => Some(unsafe { &mut *self.ptr().add(index) }),
+            false => None,
+        }
+    }
+
+    unsafe fn get_ptr_mut(&self, index: usize) -> *mut T {
+        assert!(index < N);
+        unsafe { self.ptr().add(index) }
+    }
+
+    unsafe fn reserve_maximum_concurrent_capacity(&mut self, _: usize, _: usize) -> usize {
+        N
+    }
+
+    unsafe fn reserve_maximum_concurrent_capacity_fill_with<F>(
+        &mut self,
+        _: usize,
+        _: usize,
+        _: F,
+    ) -> usize
+    where
+        F: Fn() -> T,
+    {
+        N
+    }
+
+    unsafe fn clear(&mut self, prior_len: usize) {
+        self.set_len(0);
+        let elements = core::ptr::slice_from_raw_parts_mut(self.ptr(), prior_len);
+        // SAFETY: elements within 0..prior_len are initialized and no longer belong to the vector
+        unsafe { elements.drop_in_place() };
+    }
+
+    unsafe fn ptr_iter_unchecked(&self, range: Range<usize>) -> Self::PtrIter<'_> {
+        let ptr = unsafe { self.ptr.add(range.start) };
+        FixedVecPtrIter::new(ptr, range.len())
+    }
+
+    unsafe fn into_iter(self, range: Range<usize>) -> Self::IntoIter {
+        let vec = self.into_vec(0);
+        unsafe { FixedArrayVecIntoIter::new(vec, range) }
+    }
+}
+
+impl<T, const N: usize> IntoConcurrentPinnedVec<T> for FixedArrayVec<T, N> {
+    type ConPinnedVec = ConcurrentFixedArrayVec<T, N>;
+
+    fn into_concurrent(self) -> Self::ConPinnedVec {
+        self.into()
+    }
+
+    fn into_concurrent_filled_with<F>(mut self, fill_with: F) -> Self::ConPinnedVec
+    where
+        F: Fn() -> T,
+    {
+        for _ in self.len..N {
+            self.push_or_panic(fill_with());
+        }
+        self.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use alloc::string::{String, ToString};
+    use alloc::vec::Vec;
+
+    #[test]
+    fn into_concurrent_into_inner() {
+        let mut vec = FixedArrayVec::<String, 42>::new();
+        vec.push("first".to_string());
+        let addr = &vec[0] as *const String;
+
+        let con_vec = vec.into_concurrent();
+        assert_eq!(con_vec.capacity(), 42);
+        assert_eq!(con_vec.max_capacity(), 42);
+        assert_eq!(unsafe { con_vec.get(0) }, Some(&"first".to_string()));
+
+        for i in 1..10 {
+            unsafe { con_vec.get_ptr_mut(i).write(i.to_string()) };
+        }
+
+        let vec = unsafe { con_vec.into_inner(10) };
+        assert_eq!(vec.len(), 10);
+        assert_eq!(addr, &vec[0] as *const String);
+        assert_eq!(&vec[0], "first");
+        for i in 1..10 {
+            assert_eq!(vec[i], i.to_string());
+        }
+    }
+
+    #[test]
+    fn into_concurrent_fill_with() {
+        let mut vec = FixedArrayVec::<String, 42>::new();
+        vec.push("y".to_string());
+        let con_vec = vec.into_concurrent_filled_with(|| "x".to_string());
+        let vec = unsafe { con_vec.into_inner(42) };
+        assert_eq!(&vec[0], "y");
+        assert_eq!(
+            vec[1..],
+            (1..42).map(|_| "x".to_string()).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn fill_with_panic_drops_written_elements() {
+        use alloc::rc::Rc;
+
+        let value = Rc::new(0);
+        let con_vec = FixedArrayVec::<Rc<i32>, 8>::new().into_concurrent();
+        con_vec.fill_with(0..2, || value.clone());
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            con_vec.fill_with(2..8, || {
+                assert!(Rc::strong_count(&value) < 5);
+                value.clone()
+            })
+        }));
+        assert!(result.is_err());
+        assert_eq!(Rc::strong_count(&value), 3);
+
+        let vec = unsafe { con_vec.into_inner(2) };
+        assert_eq!(vec.len(), 2);
+        drop(vec);
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
+    #[test]
+    fn grow_and_reserve() {
+        let vec = FixedArrayVec::<String, 16>::new();
+        let mut con_vec = vec.into_concurrent();
+
+        assert_eq!(con_vec.grow_to(12), Ok(16));
+        assert!(con_vec.grow_to(17).is_err());
+        assert_eq!(
+            unsafe { con_vec.reserve_maximum_concurrent_capacity(0, 32) },
+            16
+        );
+        assert_eq!(con_vec.capacity(), 16);
+    }
+
+    #[test]
+    fn slices_and_iter() {
+        let vec: FixedArrayVec<_, 7> = (0..7).collect();
+        let con_vec = vec.into_concurrent();
+
+        assert_eq!(con_vec.slices(1..4), Some(&[1, 2, 3][..]));
+        assert_eq!(con_vec.slices(4..8), None);
+
+        unsafe {
+            let vec: Vec<_> = con_vec.iter(5).copied().collect();
+            assert_eq!(vec, &[0, 1, 2, 3, 4]);
+
+            let vec: Vec<_> = con_vec.iter_over_range(2..=4).copied().collect();
+            assert_eq!(vec, &[2, 3, 4]);
+
+            let ptrs: Vec<_> = con_vec.ptr_iter_unchecked(3..6).collect();
+            assert_eq!(ptrs.iter().map(|p| **p).collect::<Vec<_>>(), &[3, 4, 5]);
+        }
+    }
+
+    #[test]
+    fn into_iter_range() {
+        let vec: FixedArrayVec<_, 20> = (0..20).map(|x| x.to_string()).collect();
+        let mut con_vec = vec.into_concurrent();
+        unsafe { con_vec.set_pinned_vec_len(0) };
+
+        for i in (0..4).chain(15..20) {
+            let _value = unsafe { con_vec.get_ptr_mut(i).read() };
+        }
+
+        let mut iter = unsafe { con_vec.into_iter(4..15) };
+        assert_eq!(iter.len(), 11);
+        assert_eq!(iter.next(), Some(4.to_string()));
+    }
+
+    #[test]
+    fn clear_and_drop() {
+        let vec: FixedArrayVec<_, 20> = (0..10).map(|x| x.to_string()).collect();
+        let mut con_vec = vec.into_concurrent();
+        for i in 10..15 {
+            unsafe { con_vec.get_ptr_mut(i).write(i.to_string()) };
+        }
+        unsafe { con_vec.clear(15) };
+
+        let vec: FixedArrayVec<_, 20> = (0..10).map(|x| x.to_string()).collect();
+        let _con_vec_dropped_without_into_inner = vec.into_concurrent();
+    }
+}
@@ -0,0 +1,144 @@
+use super::FixedArrayVec;
+use core::ops::Range;
+
+/// An iterator that moves out of a [`FixedArrayVec`].
+pub struct FixedArrayVecIntoIter<T, const N: usize> {
+    // length is set to zero; remaining elements are dropped by the iterator
+    vec: FixedArrayVec<T, N>,
+    current: usize,
+    end_exclusive: usize,
+}
+
+impl<T, const N: usize> FixedArrayVecIntoIter<T, N> {
+    /// # SAFETY
+    ///
+    /// Elements within `range` must be initialized, while the elements in the other positions
+    /// must be either uninitialized or already dropped.
+    pub(super) unsafe fn new(mut vec: FixedArrayVec<T, N>, range: Range<usize>) -> Self {
+        vec.len = 0;
+        Self {
+            vec,
+            current: range.start,
+            end_exclusive: range.end,
+        }
+    }
+}
+
+impl<T, const N: usize> Drop for FixedArrayVecIntoIter<T, N> {
+    fn drop(&mut self) {
+        if core::mem::needs_drop::<T>() {
+            let ptr = self.vec.ptr_mut();
+            for i in self.current..self.end_exclusive {
+                // SAFETY: ptr + i is in bounds and has not been moved out yet
+                unsafe { ptr.add(i).drop_in_place() };
+            }
+        }
+    }
+}
+
+impl<T, const N: usize> Iterator for FixedArrayVecIntoIter<T, N> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.current < self.end_exclusive {
+            true => {
+                // SAFETY: ptr + current is in bounds and is initialized
+                let value = unsafe { self.vec.ptr().add(self.current).read() };
+                self.current += 1;
+                Some(value)
+            }
+            false => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end_exclusive - self.current;
+        (len, Some(len))
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for FixedArrayVecIntoIter<T, N> {
+    fn len(&self) -> usize {
+        self.end_exclusive - self.current
+    }
+}
+
+impl<T, const N: usize> IntoIterator for FixedArrayVec<T, N> {
+    type Item = T;
+    type IntoIter = FixedArrayVecIntoIter<T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let range = 0..self.len;
+        // SAFETY: exactly the elements within 0..len are initialized
+        unsafe { FixedArrayVecIntoIter::new(self, range) }
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a FixedArrayVec<T, N> {
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_slice().iter()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a mut FixedArrayVec<T, N> {
+    type Item = &'a mut T;
+    type IntoIter = core::slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_mut_slice().iter_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use alloc::string::{String, ToString};
+    use alloc::vec::Vec;
+
+    #[test]
+    fn into_iter() {
+        let mut vec = FixedArrayVec::<_, 8>::new();
+        for i in 0..4 {
+            vec.push(i);
+        }
+
+        let vec_from_iter: Vec<_> = vec.into_iter().map(|x| x + 1).collect();
+        assert_eq!(vec_from_iter, &[1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn into_iter_partially_consumed() {
+        let mut vec = FixedArrayVec::<String, 8>::new();
+        for i in 0..6 {
+            vec.push(i.to_string());
+        }
+
+        let mut iter = vec.into_iter();
+        assert_eq!(iter.len(), 6);
+        assert_eq!(iter.next(), Some(0.to_string()));
+        assert_eq!(iter.next(), Some(1.to_string()));
+        assert_eq!(iter.len(), 4);
+    }
+
+    #[test]
+    fn iter_iter_mut() {
+        let mut vec = FixedArrayVec::<_, 4>::new();
+        vec.push('a');
+        vec.push('b');
+
+        for (i, x) in (&mut vec).into_iter().enumerate() {
+            *x = (b'x' + i as u8) as char;
+        }
+
+        let collected: Vec<_> = (&vec).into_iter().copied().collect();
+        assert_eq!(collected, &['x', 'y']);
+
+        let mut iter = vec.iter();
+        assert_eq!(Some(&'x'), iter.next());
+        assert_eq!(Some(&'y'), iter.next());
+        assert_eq!(None, iter.next());
+    }
+}
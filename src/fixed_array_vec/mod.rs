@@ -0,0 +1,9 @@
+mod array_vec;
+mod common_traits;
+mod con_fixed_array_vec;
+mod into_iter;
+mod pinned_vec;
+
+pub use array_vec::FixedArrayVec;
+pub use con_fixed_array_vec::ConcurrentFixedArrayVec;
+pub use into_iter::FixedArrayVecIntoIter;
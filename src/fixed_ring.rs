@@ -0,0 +1,633 @@
+use crate::{FixedVec, FixedVecFullError};
+use allocator_api2::{
+    alloc::{Allocator, Global},
+    vec::Vec as AllocVec,
+};
+use core::fmt::Debug;
+use core::iter::Chain;
+use core::ops::{Index, IndexMut};
+use core::slice::{Iter, IterMut};
+
+/// A fixed ring, `FixedRing`, is a bounded first-in-first-out queue with a strict predetermined capacity,
+/// which stores its elements in a single fixed allocation as the [`FixedVec`] does.
+///
+/// Elements are pushed to the back and popped from the front in constant time; positions of the buffer
+/// are reused in a wrap-around manner.
+///
+/// # Pinned Elements
+///
+/// The buffer is never reallocated and elements are never shifted; hence, the memory location of an element
+/// stays the same from the moment it is pushed until it is popped, overwritten or the ring is cleared.
+/// Therefore, pointers to recent entries can be held by consumers as long as the entries stay in the ring.
+///
+/// # Overwrite Policy
+///
+/// * [`push_back`](FixedRing::push_back) rejects the new element when the ring is full, and
+/// * [`push_back_overwriting`](FixedRing::push_back_overwriting) evicts the oldest element instead, writing
+///   the new element to the freed slot.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_fixed_vec::prelude::*;
+///
+/// let mut ring = FixedRing::new(3);
+///
+/// ring.push_back(0).unwrap();
+/// ring.push_back(1).unwrap();
+/// let addr1 = &ring[1] as *const i32;
+///
+/// assert_eq!(ring.pop_front(), Some(0));
+/// ring.push_back(2).unwrap();
+/// ring.push_back(3).unwrap(); // wraps around
+///
+/// assert!(ring.is_full());
+/// assert_eq!(ring.as_slices(), (&[1, 2][..], &[3][..]));
+///
+/// // element 1 did not move
+/// assert_eq!(addr1, &ring[0] as *const i32);
+///
+/// assert_eq!(ring.push_back_overwriting(4), Some(1));
+/// assert_eq!(ring.iter().copied().collect::<Vec<_>>(), [2, 3, 4]);
+/// ```
+pub struct FixedRing<T, A: Allocator = Global> {
+    // owns the allocation; its length is always zero and elements are managed by the ring
+    data: AllocVec<T, A>,
+    capacity: usize,
+    head: usize,
+    len: usize,
+}
+
+impl<T> FixedRing<T> {
+    /// Creates a new empty ring with the given `fixed_capacity`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_fixed_vec::prelude::*;
+    ///
+    /// let ring = FixedRing::<char>::new(7);
+    /// assert_eq!(7, ring.capacity());
+    /// assert!(ring.is_empty());
+    /// ```
+    pub fn new(fixed_capacity: usize) -> Self {
+        Self::new_in(fixed_capacity, Global)
+    }
+}
+
+impl<T, A: Allocator> FixedRing<T, A> {
+    /// Creates a new empty ring with the given `fixed_capacity` whose buffer is allocated by `alloc`.
+    pub fn new_in(fixed_capacity: usize, alloc: A) -> Self {
+        Self {
+            data: AllocVec::with_capacity_in(fixed_capacity, alloc),
+            capacity: fixed_capacity,
+            head: 0,
+            len: 0,
+        }
+    }
+
+    /// Returns the fixed capacity of the ring.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of elements in the ring.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the ring does not contain any elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the available room for new elements; i.e., `capacity() - len()`.
+    pub fn room(&self) -> usize {
+        self.capacity - self.len
+    }
+
+    /// Returns true if the ring is full; i.e., `len() == capacity()`.
+    pub fn is_full(&self) -> bool {
+        self.len == self.capacity
+    }
+
+    /// Returns a reference to the allocator of the ring.
+    pub fn allocator(&self) -> &A {
+        self.data.allocator()
+    }
+
+    /// Appends the `value` to the back of the ring if there is available room;
+    /// returns back the `value` in a [`FixedVecFullError`] otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_fixed_vec::prelude::*;
+    ///
+    /// let mut ring = FixedRing::new(1);
+    /// assert!(ring.push_back('a').is_ok());
+    ///
+    /// let error = ring.push_back('b').unwrap_err();
+    /// assert_eq!(error.into_rejected(), 'b');
+    /// assert_eq!(ring.front(), Some(&'a'));
+    /// ```
+    pub fn push_back(&mut self, value: T) -> Result<(), FixedVecFullError<T>> {
+        match self.is_full() {
+            true => Err(FixedVecFullError::new(value, self.capacity, self.len)),
+            false => {
+                let slot = self.slot(self.len);
+                unsafe { self.ptr_mut(slot).write(value) };
+                self.len += 1;
+                Ok(())
+            }
+        }
+    }
+
+    /// Appends the `value` to the back of the ring; if the ring is full, the oldest element
+    /// is removed from the front and returned, and the `value` is written to its slot.
+    ///
+    /// When the capacity of the ring is zero, the `value` itself is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_fixed_vec::prelude::*;
+    ///
+    /// let mut ring = FixedRing::new(2);
+    /// assert_eq!(ring.push_back_overwriting(0), None);
+    /// assert_eq!(ring.push_back_overwriting(1), None);
+    ///
+    /// let addr0 = ring.front().unwrap() as *const i32;
+    /// assert_eq!(ring.push_back_overwriting(2), Some(0));
+    ///
+    /// // new element is written to the slot of the evicted one
+    /// assert_eq!(addr0, ring.back().unwrap() as *const i32);
+    /// assert_eq!(ring.iter().copied().collect::<Vec<_>>(), [1, 2]);
+    /// ```
+    pub fn push_back_overwriting(&mut self, value: T) -> Option<T> {
+        match self.push_back(value) {
+            Ok(()) => None,
+            Err(error) if self.capacity == 0 => Some(error.into_rejected()),
+            Err(error) => {
+                let oldest = self.pop_front();
+                let pushed = self.push_back(error.into_rejected());
+                debug_assert!(pushed.is_ok());
+                oldest
+            }
+        }
+    }
+
+    /// Removes the oldest element from the front of the ring and returns it; None if the ring is empty.
+    pub fn pop_front(&mut self) -> Option<T> {
+        match self.is_empty() {
+            true => None,
+            false => {
+                let value = unsafe { self.ptr_mut(self.head).read() };
+                self.head = self.slot(1);
+                self.len -= 1;
+                Some(value)
+            }
+        }
+    }
+
+    /// Returns a reference to the oldest element; None if the ring is empty.
+    pub fn front(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    /// Returns a reference to the most recently pushed element; None if the ring is empty.
+    pub fn back(&self) -> Option<&T> {
+        self.len.checked_sub(1).and_then(|i| self.get(i))
+    }
+
+    /// Returns a reference to the `index`-th oldest element; None if `index >= len()`.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        match index < self.len {
+            true => Some(unsafe { &*self.ptr(self.slot(index)) }),
+            false => None,
+        }
+    }
+
+    /// Returns a mutable reference to the `index`-th oldest element; None if `index >= len()`.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        match index < self.len {
+            true => Some(unsafe { &mut *self.ptr_mut(self.slot(index)) }),
+            false => None,
+        }
+    }
+
+    /// Returns the pair of slices which together contain the elements of the ring from the oldest to the newest.
+    ///
+    /// The second slice is non-empty only if the elements wrap around the end of the buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_fixed_vec::prelude::*;
+    ///
+    /// let mut ring = FixedRing::new(3);
+    /// for i in 0..3 {
+    ///     ring.push_back(i).unwrap();
+    /// }
+    /// assert_eq!(ring.as_slices(), (&[0, 1, 2][..], &[][..]));
+    ///
+    /// ring.push_back_overwriting(3);
+    /// assert_eq!(ring.as_slices(), (&[1, 2][..], &[3][..]));
+    /// ```
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        let (a, b) = self.slice_lengths();
+        unsafe {
+            (
+                core::slice::from_raw_parts(self.ptr(self.head), a),
+                core::slice::from_raw_parts(self.ptr(0), b),
+            )
+        }
+    }
+
+    /// Returns the pair of mutable slices which together contain the elements of the ring from the oldest to the newest.
+    ///
+    /// The second slice is non-empty only if the elements wrap around the end of the buffer.
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        let (a, b) = self.slice_lengths();
+        let head = self.head;
+        let ptr = self.data.as_mut_ptr();
+        // SAFETY: the slices are disjoint since a + b = len <= capacity
+        unsafe {
+            (
+                core::slice::from_raw_parts_mut(ptr.add(head), a),
+                core::slice::from_raw_parts_mut(ptr, b),
+            )
+        }
+    }
+
+    /// Returns an iterator over the elements of the ring from the oldest to the newest.
+    pub fn iter(&self) -> Chain<Iter<'_, T>, Iter<'_, T>> {
+        let (a, b) = self.as_slices();
+        a.iter().chain(b.iter())
+    }
+
+    /// Returns an iterator over mutable references to the elements of the ring from the oldest to the newest.
+    pub fn iter_mut(&mut self) -> Chain<IterMut<'_, T>, IterMut<'_, T>> {
+        let (a, b) = self.as_mut_slices();
+        a.iter_mut().chain(b.iter_mut())
+    }
+
+    /// Clears the ring, removing and dropping all elements.
+    pub fn clear(&mut self) {
+        let (a, b) = self.as_mut_slices();
+        let (a, b) = (a as *mut [T], b as *mut [T]);
+        // the ring is emptied before dropping so that a panicking drop cannot lead to a double drop
+        self.head = 0;
+        self.len = 0;
+        unsafe {
+            // the guard drops the second slice even if a destructor of the first slice panics
+            let _back = Dropper(b);
+            core::ptr::drop_in_place(a);
+        }
+    }
+
+    // helpers
+
+    /// Position in the buffer of the `index`-th element counting from the head.
+    #[inline(always)]
+    fn slot(&self, index: usize) -> usize {
+        let slot = self.head + index;
+        match slot >= self.capacity {
+            true => slot - self.capacity,
+            false => slot,
+        }
+    }
+
+    #[inline(always)]
+    fn slice_lengths(&self) -> (usize, usize) {
+        let to_end = self.capacity - self.head;
+        match self.len <= to_end {
+            true => (self.len, 0),
+            false => (to_end, self.len - to_end),
+        }
+    }
+
+    #[inline(always)]
+    fn ptr(&self, slot: usize) -> *const T {
+        unsafe { self.data.as_ptr().add(slot) }
+    }
+
+    #[inline(always)]
+    fn ptr_mut(&mut self, slot: usize) -> *mut T {
+        unsafe { self.data.as_mut_ptr().add(slot) }
+    }
+}
+
+/// Drops the elements of the slice in place when dropped.
+struct Dropper<T>(*mut [T]);
+
+impl<T> Drop for Dropper<T> {
+    fn drop(&mut self) {
+        unsafe { core::ptr::drop_in_place(self.0) }
+    }
+}
+
+impl<T, A: Allocator> Drop for FixedRing<T, A> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T, A: Allocator> From<FixedVec<T, A>> for FixedRing<T, A> {
+    /// Converts the fixed vector into a ring reusing its allocation.
+    ///
+    /// Capacity of the ring is equal to the capacity of the vector, and elements of the vector,
+    /// which keep their memory locations, become the elements of the ring from the oldest to the newest.
+    fn from(vec: FixedVec<T, A>) -> Self {
+        let (mut data, capacity) = (vec.data, vec.capacity);
+        let len = data.len();
+        // SAFETY: ownership of the elements is transferred to the ring
+        unsafe { data.set_len(0) };
+        Self {
+            data,
+            capacity,
+            head: 0,
+            len,
+        }
+    }
+}
+
+impl<T: Debug, A: Allocator> Debug for FixedRing<T, A> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FixedRing")
+            .field("capacity", &self.capacity)
+            .field("data", &DebugElements(self))
+            .finish()
+    }
+}
+
+struct DebugElements<'a, T, A: Allocator>(&'a FixedRing<T, A>);
+
+impl<T: Debug, A: Allocator> Debug for DebugElements<'_, T, A> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.0.iter()).finish()
+    }
+}
+
+impl<T, A: Allocator> Index<usize> for FixedRing<T, A> {
+    type Output = T;
+
+    /// Returns a reference to the `index`-th oldest element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= len()`.
+    fn index(&self, index: usize) -> &Self::Output {
+        self.get(index).expect(ERR_MSG_OUT_OF_BOUNDS)
+    }
+}
+
+impl<T, A: Allocator> IndexMut<usize> for FixedRing<T, A> {
+    /// Returns a mutable reference to the `index`-th oldest element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= len()`.
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        self.get_mut(index).expect(ERR_MSG_OUT_OF_BOUNDS)
+    }
+}
+
+impl<'a, T, A: Allocator> IntoIterator for &'a FixedRing<T, A> {
+    type Item = &'a T;
+    type IntoIter = Chain<Iter<'a, T>, Iter<'a, T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, A: Allocator> IntoIterator for &'a mut FixedRing<T, A> {
+    type Item = &'a mut T;
+    type IntoIter = Chain<IterMut<'a, T>, IterMut<'a, T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+const ERR_MSG_OUT_OF_BOUNDS: &str = "index is out of bounds of the FixedRing.";
+
+#[cfg(test)]
+mod tests {
+    use crate::helpers::drop_counter::{DropCounter, Tracked};
+    use crate::prelude::*;
+    use alloc::format;
+    use alloc::string::{String, ToString};
+    use alloc::vec::Vec;
+
+    fn to_vec<T: Clone>(ring: &FixedRing<T>) -> Vec<T> {
+        ring.iter().cloned().collect()
+    }
+
+    #[test]
+    fn push_back_pop_front() {
+        let mut ring = FixedRing::new(4);
+        assert!(ring.is_empty());
+        assert_eq!(ring.pop_front(), None);
+
+        for i in 0..4 {
+            assert!(ring.push_back(i.to_string()).is_ok());
+        }
+        assert!(ring.is_full());
+        assert_eq!(ring.room(), 0);
+
+        let error = ring.push_back(4.to_string()).expect_err("is-full");
+        assert_eq!(error.len(), 4);
+        assert_eq!(error.into_rejected(), "4");
+
+        assert_eq!(ring.pop_front().as_deref(), Some("0"));
+        assert_eq!(ring.pop_front().as_deref(), Some("1"));
+        assert!(ring.push_back(4.to_string()).is_ok());
+
+        assert_eq!(ring.len(), 3);
+        assert_eq!(ring.front().map(String::as_str), Some("2"));
+        assert_eq!(ring.back().map(String::as_str), Some("4"));
+        assert_eq!(to_vec(&ring), ["2", "3", "4"]);
+    }
+
+    #[test]
+    fn wrap_around_keeps_elements_pinned() {
+        let cap = 5;
+        let mut ring = FixedRing::new(cap);
+        let mut addresses = Vec::new();
+
+        for i in 0..100 {
+            if ring.is_full() {
+                let popped = ring.pop_front().expect("is-full");
+                assert_eq!(popped, i - cap);
+            }
+            ring.push_back(i).expect("has-room");
+            addresses.push(ring.back().expect("non-empty") as *const usize);
+
+            // all elements in the ring are at the addresses they were pushed to
+            for (j, x) in ring.iter().enumerate() {
+                let pushed_at = i + 1 - ring.len() + j;
+                assert_eq!(*x, pushed_at);
+                assert_eq!(x as *const usize, addresses[pushed_at]);
+            }
+        }
+    }
+
+    #[test]
+    fn push_back_overwriting() {
+        let mut ring = FixedRing::new(3);
+        for i in 0..3 {
+            assert_eq!(ring.push_back_overwriting(i), None);
+        }
+        for i in 3..10 {
+            assert_eq!(ring.push_back_overwriting(i), Some(i - 3));
+            assert_eq!(to_vec(&ring), [i - 2, i - 1, i]);
+        }
+
+        let mut ring = FixedRing::new(0);
+        assert_eq!(ring.push_back_overwriting('x'), Some('x'));
+        assert!(ring.is_empty());
+    }
+
+    #[test]
+    fn as_slices() {
+        let mut ring = FixedRing::new(4);
+        assert_eq!(ring.as_slices(), (&[][..], &[][..]));
+
+        for i in 0..4 {
+            ring.push_back(i).expect("has-room");
+        }
+        assert_eq!(ring.as_slices(), (&[0, 1, 2, 3][..], &[][..]));
+
+        ring.push_back_overwriting(4);
+        ring.push_back_overwriting(5);
+        assert_eq!(ring.as_slices(), (&[2, 3][..], &[4, 5][..]));
+
+        let (a, b) = ring.as_mut_slices();
+        a[0] = 20;
+        b[1] = 50;
+        assert_eq!(to_vec(&ring), [20, 3, 4, 50]);
+
+        for x in &mut ring {
+            *x += 1;
+        }
+        assert_eq!(to_vec(&ring), [21, 4, 5, 51]);
+
+        while ring.len() > 1 {
+            ring.pop_front();
+        }
+        assert_eq!(ring.as_slices(), (&[51][..], &[][..]));
+    }
+
+    #[test]
+    fn index() {
+        let mut ring = FixedRing::new(3);
+        for i in 0..5 {
+            ring.push_back_overwriting(i);
+        }
+        assert_eq!((ring[0], ring[1], ring[2]), (2, 3, 4));
+        ring[1] = 30;
+        assert_eq!(ring.get(1), Some(&30));
+        assert_eq!(ring.get(3), None);
+        assert_eq!(ring.get_mut(3), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_out_of_bounds() {
+        let mut ring = FixedRing::new(3);
+        ring.push_back(0).expect("has-room");
+        let _ = ring[1];
+    }
+
+    #[test]
+    fn drops_each_element_once() {
+        let drops = DropCounter::new();
+        let mut ring = FixedRing::new(3);
+
+        for _ in 0..5 {
+            let _ = ring.push_back_overwriting(drops.track());
+        }
+        assert_eq!(drops.dropped(), 2);
+
+        let popped = ring.pop_front();
+        assert_eq!(drops.dropped(), 2);
+        drop(popped);
+        assert_eq!(drops.dropped(), 3);
+
+        ring.push_back(drops.track()).expect("has-room");
+        ring.push_back(drops.track()).expect_err("is-full");
+        assert_eq!(drops.dropped(), 4);
+
+        ring.clear();
+        assert_eq!(drops.dropped(), 7);
+        assert!(ring.is_empty());
+
+        ring.push_back(drops.track()).expect("has-room");
+        drop(ring);
+        assert_eq!(drops.dropped(), 8);
+    }
+
+    #[test]
+    fn clear_drops_second_slice_when_a_drop_panics() {
+        struct PanicOnDrop {
+            _tracked: Tracked,
+            panics: bool,
+        }
+
+        impl Drop for PanicOnDrop {
+            fn drop(&mut self) {
+                assert!(!self.panics, "failed to drop");
+            }
+        }
+
+        let drops = DropCounter::new();
+        let mut ring = FixedRing::new(4);
+        for i in 0..6 {
+            let _ = ring.push_back_overwriting(PanicOnDrop {
+                _tracked: drops.track(),
+                panics: i == 3,
+            });
+        }
+        assert_eq!(ring.as_slices().0.len(), 2);
+        assert_eq!(ring.as_slices().1.len(), 2);
+        assert_eq!(drops.dropped(), 2);
+
+        let result = std::panic::catch_unwind(core::panic::AssertUnwindSafe(|| ring.clear()));
+        assert!(result.is_err());
+        assert!(ring.is_empty());
+        assert_eq!(drops.dropped(), 6);
+
+        drop(ring);
+        assert_eq!(drops.dropped(), 6);
+    }
+
+    #[test]
+    fn from_fixed_vec() {
+        let mut vec = FixedVec::new(4);
+        vec.extend_from_slice(&[0, 1, 2]);
+        let addr0 = &vec[0] as *const i32;
+
+        let mut ring = FixedRing::from(vec);
+        assert_eq!(ring.capacity(), 4);
+        assert_eq!(to_vec(&ring), [0, 1, 2]);
+        assert_eq!(addr0, &ring[0] as *const i32);
+
+        ring.push_back(3).expect("has-room");
+        assert_eq!(ring.push_back_overwriting(4), Some(0));
+        assert_eq!(to_vec(&ring), [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn debug() {
+        let mut ring = FixedRing::new(3);
+        for i in 0..4 {
+            ring.push_back_overwriting(i);
+        }
+        assert_eq!(
+            format!("{:?}", ring),
+            "FixedRing { capacity: 3, data: [1, 2, 3] }"
+        );
+    }
+}
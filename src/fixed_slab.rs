@@ -0,0 +1,511 @@
+use crate::FixedVecFullError;
+use allocator_api2::{
+    alloc::{Allocator, Global},
+    vec::Vec as AllocVec,
+};
+use core::fmt::Debug;
+use core::ops::{Index, IndexMut};
+
+/// A fixed slab, `FixedSlab`, is a slot map with a strict predetermined capacity which stores its elements
+/// in a single fixed allocation as the [`FixedVec`](crate::FixedVec) does.
+///
+/// Inserting an element returns its key, which is the position of its slot in the buffer.
+/// Removing an element puts its slot on a free list, which is reused by the subsequent insertions.
+///
+/// # Pinned Elements
+///
+/// Unlike [`FixedVec::remove`](orx_pinned_vec::PinnedVec::remove), removing an element from the slab
+/// does not shift the elements after it. No element is ever moved; hence, the memory location of an element
+/// stays the same from the moment it is inserted until it is removed or the slab is cleared.
+///
+/// Note that the key of a removed element is reused by a later insertion.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_fixed_vec::prelude::*;
+///
+/// let mut slab = FixedSlab::new(4);
+///
+/// let a = slab.insert('a');
+/// let b = slab.insert('b');
+/// let c = slab.insert('c');
+/// let addr_c = &slab[c] as *const char;
+///
+/// assert_eq!(slab.remove(b), 'b');
+/// assert_eq!(slab.len(), 2);
+/// assert_eq!(slab.get(b), None);
+///
+/// // neighbours are not moved
+/// assert_eq!(addr_c, &slab[c] as *const char);
+/// assert_eq!(slab.index_of(&slab[c]), Some(c));
+///
+/// // freed slot is reused
+/// let d = slab.insert('d');
+/// assert_eq!(d, b);
+///
+/// let elements: Vec<_> = slab.iter().collect();
+/// assert_eq!(elements, [(a, &'a'), (d, &'d'), (c, &'c')]);
+/// ```
+pub struct FixedSlab<T, A: Allocator = Global> {
+    // slots are pushed up to the fixed capacity, and are never removed except by clear
+    data: AllocVec<Slot<T>, A>,
+    capacity: usize,
+    len: usize,
+    // head of the free list of vacant slots
+    free: Option<usize>,
+}
+
+enum Slot<T> {
+    Occupied(T),
+    Vacant { next_free: Option<usize> },
+}
+
+impl<T> FixedSlab<T> {
+    /// Creates a new empty slab with the given `fixed_capacity`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_fixed_vec::prelude::*;
+    ///
+    /// let slab = FixedSlab::<String>::new(7);
+    /// assert_eq!(7, slab.capacity());
+    /// assert!(slab.is_empty());
+    /// ```
+    pub fn new(fixed_capacity: usize) -> Self {
+        Self::new_in(fixed_capacity, Global)
+    }
+}
+
+impl<T, A: Allocator> FixedSlab<T, A> {
+    /// Creates a new empty slab with the given `fixed_capacity` whose buffer is allocated by `alloc`.
+    pub fn new_in(fixed_capacity: usize, alloc: A) -> Self {
+        Self {
+            data: AllocVec::with_capacity_in(fixed_capacity, alloc),
+            capacity: fixed_capacity,
+            len: 0,
+            free: None,
+        }
+    }
+
+    /// Returns the fixed capacity of the slab.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of elements in the slab.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the slab does not contain any elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the available room for new elements; i.e., `capacity() - len()`.
+    pub fn room(&self) -> usize {
+        self.capacity - self.len
+    }
+
+    /// Returns true if the slab is full; i.e., `len() == capacity()`.
+    pub fn is_full(&self) -> bool {
+        self.len == self.capacity
+    }
+
+    /// Returns a reference to the allocator of the slab.
+    pub fn allocator(&self) -> &A {
+        self.data.allocator()
+    }
+
+    /// Returns the key that the next inserted element will receive; None if the slab is full.
+    pub fn vacant_key(&self) -> Option<usize> {
+        match self.free {
+            Some(key) => Some(key),
+            None => match self.data.len() < self.capacity {
+                true => Some(self.data.len()),
+                false => None,
+            },
+        }
+    }
+
+    /// Inserts the `value` into a vacant slot and returns its key if there is available room;
+    /// returns back the `value` in a [`FixedVecFullError`] otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_fixed_vec::prelude::*;
+    ///
+    /// let mut slab = FixedSlab::new(1);
+    /// assert_eq!(slab.try_insert('a'), Ok(0));
+    ///
+    /// let error = slab.try_insert('b').unwrap_err();
+    /// assert_eq!(error.into_rejected(), 'b');
+    /// ```
+    pub fn try_insert(&mut self, value: T) -> Result<usize, FixedVecFullError<T>> {
+        match self.free {
+            Some(key) => {
+                let slot = &mut self.data[key];
+                if let Slot::Vacant { next_free } = slot {
+                    self.free = *next_free;
+                }
+                *slot = Slot::Occupied(value);
+                self.len += 1;
+                Ok(key)
+            }
+            None => match self.data.len() < self.capacity {
+                true => {
+                    let key = self.data.len();
+                    self.data.push(Slot::Occupied(value));
+                    self.len += 1;
+                    Ok(key)
+                }
+                false => Err(FixedVecFullError::new(value, self.capacity, self.len)),
+            },
+        }
+    }
+
+    /// Inserts the `value` into a vacant slot and returns its key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slab is full; i.e., if `self.is_full()`.
+    pub fn insert(&mut self, value: T) -> usize {
+        self.try_insert(value).expect(ERR_MSG_OUT_OF_ROOM)
+    }
+
+    /// Removes the element with the given `key` and returns it; None if the slot of the `key` is vacant.
+    ///
+    /// The slot is put on the free list; no other element is moved.
+    pub fn try_remove(&mut self, key: usize) -> Option<T> {
+        let slot = self.data.get_mut(key)?;
+        if let Slot::Vacant { .. } = slot {
+            return None;
+        }
+
+        let vacant = Slot::Vacant {
+            next_free: self.free,
+        };
+        match core::mem::replace(slot, vacant) {
+            Slot::Occupied(value) => {
+                self.free = Some(key);
+                self.len -= 1;
+                Some(value)
+            }
+            Slot::Vacant { .. } => None,
+        }
+    }
+
+    /// Removes the element with the given `key` and returns it.
+    ///
+    /// The slot is put on the free list; no other element is moved.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slot of the `key` is vacant; i.e., if `!self.contains(key)`.
+    pub fn remove(&mut self, key: usize) -> T {
+        self.try_remove(key).expect(ERR_MSG_VACANT)
+    }
+
+    /// Returns true if the slot of the `key` is occupied.
+    pub fn contains(&self, key: usize) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Returns a reference to the element with the given `key`; None if the slot is vacant.
+    pub fn get(&self, key: usize) -> Option<&T> {
+        match self.data.get(key) {
+            Some(Slot::Occupied(x)) => Some(x),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the element with the given `key`; None if the slot is vacant.
+    pub fn get_mut(&mut self, key: usize) -> Option<&mut T> {
+        match self.data.get_mut(key) {
+            Some(Slot::Occupied(x)) => Some(x),
+            _ => None,
+        }
+    }
+
+    /// Returns the key of the `element` if it belongs to the slab; None otherwise.
+    ///
+    /// Note that `T: Eq` is not required; reference equality is used.
+    /// The complexity of this method is constant.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_fixed_vec::prelude::*;
+    ///
+    /// let mut slab = FixedSlab::new(4);
+    /// slab.insert(10);
+    /// let key = slab.insert(20);
+    ///
+    /// let element = &slab[key];
+    /// assert_eq!(slab.index_of(element), Some(key));
+    ///
+    /// let other = 20;
+    /// assert_eq!(slab.index_of(&other), None);
+    /// ```
+    pub fn index_of(&self, element: &T) -> Option<usize> {
+        let slot_size = core::mem::size_of::<Slot<T>>();
+        let offset = (element as *const T)
+            .addr()
+            .checked_sub(self.data.as_ptr().addr())?;
+        match self.data.get(offset / slot_size) {
+            Some(Slot::Occupied(x)) if core::ptr::eq(x, element) => Some(offset / slot_size),
+            _ => None,
+        }
+    }
+
+    /// Returns whether or not the `element` with the given reference belongs to the slab.
+    ///
+    /// Note that `T: Eq` is not required; memory address is used.
+    pub fn contains_reference(&self, element: &T) -> bool {
+        self.index_of(element).is_some()
+    }
+
+    /// Returns an iterator over the keys and references to the elements of the occupied slots,
+    /// in the order of their keys.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, &T)> {
+        self.data
+            .iter()
+            .enumerate()
+            .filter_map(|(key, slot)| match slot {
+                Slot::Occupied(x) => Some((key, x)),
+                Slot::Vacant { .. } => None,
+            })
+    }
+
+    /// Returns an iterator over the keys and mutable references to the elements of the occupied slots,
+    /// in the order of their keys.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (usize, &mut T)> {
+        self.data
+            .iter_mut()
+            .enumerate()
+            .filter_map(|(key, slot)| match slot {
+                Slot::Occupied(x) => Some((key, x)),
+                Slot::Vacant { .. } => None,
+            })
+    }
+
+    /// Clears the slab, removing and dropping all elements.
+    pub fn clear(&mut self) {
+        self.data.clear();
+        self.len = 0;
+        self.free = None;
+    }
+}
+
+impl<T: Debug, A: Allocator> Debug for FixedSlab<T, A> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FixedSlab")
+            .field("capacity", &self.capacity)
+            .field("data", &DebugElements(self))
+            .finish()
+    }
+}
+
+struct DebugElements<'a, T, A: Allocator>(&'a FixedSlab<T, A>);
+
+impl<T: Debug, A: Allocator> Debug for DebugElements<'_, T, A> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_map().entries(self.0.iter()).finish()
+    }
+}
+
+impl<T, A: Allocator> Index<usize> for FixedSlab<T, A> {
+    type Output = T;
+
+    /// Returns a reference to the element with the given `key`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slot of the `key` is vacant.
+    fn index(&self, key: usize) -> &Self::Output {
+        self.get(key).expect(ERR_MSG_VACANT)
+    }
+}
+
+impl<T, A: Allocator> IndexMut<usize> for FixedSlab<T, A> {
+    /// Returns a mutable reference to the element with the given `key`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slot of the `key` is vacant.
+    fn index_mut(&mut self, key: usize) -> &mut Self::Output {
+        self.get_mut(key).expect(ERR_MSG_VACANT)
+    }
+}
+
+const ERR_MSG_OUT_OF_ROOM: &str =
+    "FixedSlab reached its fixed capacity; it does not have any vacant slots left.";
+
+const ERR_MSG_VACANT: &str = "slot of the key is vacant in the FixedSlab.";
+
+#[cfg(test)]
+mod tests {
+    use crate::helpers::drop_counter::DropCounter;
+    use crate::prelude::*;
+    use alloc::format;
+    use alloc::string::ToString;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn insert_remove() {
+        let mut slab = FixedSlab::new(4);
+        let keys: Vec<_> = (0..4).map(|i| slab.insert(i.to_string())).collect();
+        assert_eq!(keys, [0, 1, 2, 3]);
+        assert!(slab.is_full());
+        assert_eq!(slab.vacant_key(), None);
+
+        let error = slab.try_insert(4.to_string()).expect_err("is-full");
+        assert_eq!(error.capacity(), 4);
+        assert_eq!(error.into_rejected(), "4");
+
+        assert_eq!(slab.remove(1), "1");
+        assert_eq!(slab.remove(3), "3");
+        assert_eq!(slab.try_remove(3), None);
+        assert_eq!(slab.try_remove(42), None);
+        assert_eq!(slab.len(), 2);
+        assert_eq!(slab.room(), 2);
+        assert!(!slab.contains(1));
+        assert!(slab.contains(2));
+
+        // last freed slot is reused first
+        assert_eq!(slab.vacant_key(), Some(3));
+        assert_eq!(slab.insert("x".to_string()), 3);
+        assert_eq!(slab.insert("y".to_string()), 1);
+        assert_eq!(slab.vacant_key(), None);
+
+        let elements: Vec<_> = slab.iter().map(|(k, x)| (k, x.as_str())).collect();
+        assert_eq!(elements, [(0, "0"), (1, "y"), (2, "2"), (3, "x")]);
+    }
+
+    #[test]
+    fn elements_are_pinned() {
+        let mut slab = FixedSlab::new(100);
+        let keys: Vec<_> = (0..100).map(|i| slab.insert(i)).collect();
+        let addresses: Vec<_> = keys.iter().map(|k| &slab[*k] as *const usize).collect();
+
+        for k in keys.iter().filter(|k| *k % 3 == 0) {
+            assert_eq!(slab.remove(*k), *k);
+        }
+        for i in 0..34 {
+            slab.insert(1000 + i);
+        }
+        assert!(slab.is_full());
+
+        for k in keys.iter().filter(|k| *k % 3 != 0) {
+            assert_eq!(slab[*k], *k);
+            assert_eq!(&slab[*k] as *const usize, addresses[*k]);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn insert_when_full() {
+        let mut slab = FixedSlab::new(1);
+        slab.insert(0);
+        slab.insert(1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn remove_vacant() {
+        let mut slab = FixedSlab::new(2);
+        let key = slab.insert(0);
+        slab.remove(key);
+        slab.remove(key);
+    }
+
+    #[test]
+    fn index_of_and_contains_reference() {
+        let mut slab = FixedSlab::new(4);
+        let keys: Vec<_> = (0..4).map(|i| slab.insert(i.to_string())).collect();
+
+        for k in &keys {
+            let element = &slab[*k];
+            assert_eq!(slab.index_of(element), Some(*k));
+            assert!(slab.contains_reference(element));
+        }
+
+        let other = "1".to_string();
+        assert_eq!(slab.index_of(&other), None);
+        assert!(!slab.contains_reference(&other));
+
+        let removed = slab.remove(1);
+        let element = &slab[0];
+        assert_eq!(slab.index_of(element), Some(0));
+        assert!(!slab.contains_reference(&removed));
+    }
+
+    #[test]
+    fn index_of_zero_sized() {
+        let mut slab = FixedSlab::new(3);
+        let a = slab.insert(());
+        let b = slab.insert(());
+        assert_eq!(slab.index_of(&slab[a]), Some(a));
+        assert_eq!(slab.index_of(&slab[b]), Some(b));
+    }
+
+    #[test]
+    fn iter_mut() {
+        let mut slab = FixedSlab::new(5);
+        for i in 0..5 {
+            slab.insert(i);
+        }
+        slab.remove(2);
+
+        for (k, x) in slab.iter_mut() {
+            *x += 10 * k;
+        }
+        let elements: Vec<_> = slab.iter().map(|(_, x)| *x).collect();
+        assert_eq!(elements, [0, 11, 33, 44]);
+
+        slab[1] = 1;
+        assert_eq!(slab.get_mut(1), Some(&mut 1));
+        assert_eq!(slab.get_mut(2), None);
+    }
+
+    #[test]
+    fn drops_each_element_once() {
+        let drops = DropCounter::new();
+        let mut slab = FixedSlab::new(4);
+
+        for _ in 0..4 {
+            slab.insert(drops.track());
+        }
+        drop(slab.remove(2));
+        assert_eq!(drops.dropped(), 1);
+
+        let _ = slab.try_insert(drops.track());
+        let _ = slab.try_insert(drops.track());
+        assert_eq!(drops.dropped(), 2);
+
+        slab.clear();
+        assert_eq!(drops.dropped(), 6);
+        assert!(slab.is_empty());
+        assert_eq!(slab.vacant_key(), Some(0));
+
+        slab.insert(drops.track());
+        drop(slab);
+        assert_eq!(drops.dropped(), 7);
+    }
+
+    #[test]
+    fn debug() {
+        let mut slab = FixedSlab::new(3);
+        for i in 0..3 {
+            slab.insert(i);
+        }
+        slab.remove(1);
+        assert_eq!(
+            format!("{:?}", slab),
+            "FixedSlab { capacity: 3, data: {0: 0, 2: 2} }"
+        );
+    }
+}
@@ -1,4 +1,13 @@
+use crate::helpers::range::{range_end, range_start};
+use crate::helpers::vec::{from_std_vec, into_std_vec, is_zst};
+use crate::{FixedVecBuilder, FixedVecFullError};
 use alloc::vec::Vec;
+use allocator_api2::{
+    alloc::{Allocator, Global},
+    vec::{Drain, Vec as AllocVec},
+};
+use core::iter::{Chain, Once, once};
+use core::ops::RangeBounds;
 
 /// A fixed vector, `FixedVec`, is a vector with a strict predetermined capacity
 /// (see [`SplitVec`](https://crates.io/crates/orx-split-vec) for dynamic capacity version).
@@ -14,8 +23,26 @@ use alloc::vec::Vec;
 ///     * This allows the fixed vec to be converted into an [`ImpVec`](https://crates.io/crates/orx-imp-vec)
 ///       to enable immutable-push operations which allows for
 ///       convenient, efficient and safe implementations of self-referencing data structures.
-pub struct FixedVec<T> {
-    pub(crate) data: Vec<T>,
+///
+/// The buffer is allocated by the allocator `A` which defaults to the [`Global`] allocator.
+/// Any allocator implementing the [`Allocator`] trait can be used through [`FixedVec::new_in`];
+/// see the `allocator_api2` crate, or the `nightly` feature to use the unstable allocator api of the standard library.
+/// The inherent methods of the vector work with any allocator, including stateful allocators such as `&Bump`.
+/// Since the `PinnedVec` trait requires a pseudo-default value which is created without an existing vector,
+/// `PinnedVec` is implemented for allocators which implement `Default`,
+/// and `IntoConcurrentPinnedVec` for allocators which additionally implement `Clone`.
+///
+/// # Zero-Sized Types
+///
+/// A vector of a zero-sized type does not allocate, and the standard vector reports its capacity as `usize::MAX`.
+/// The fixed vector, on the other hand, keeps the fixed capacity that it is created with for zero-sized types as well;
+/// hence, `FixedVec::<()>::new(4)` can hold at most 4 elements.
+pub struct FixedVec<T, A: Allocator = Global> {
+    pub(crate) data: AllocVec<T, A>,
+    // fixed capacity; equal to the capacity of `data` except for zero-sized types
+    pub(crate) capacity: usize,
+    // bumped whenever an element at an existing position is removed or moved to invalidate `FixedVecIdx` handles
+    pub(crate) generation: usize,
 }
 
 impl<T> FixedVec<T> {
@@ -23,6 +50,10 @@ impl<T> FixedVec<T> {
     ///
     /// Note that the vector can never grow beyond this capacity.
     ///
+    /// # Panics
+    ///
+    /// Panics if the required memory exceeds `isize::MAX` bytes.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -34,11 +65,224 @@ impl<T> FixedVec<T> {
     /// assert_eq!(7, vec.capacity());
     /// ```
     pub fn new(fixed_capacity: usize) -> Self {
+        Self::new_in(fixed_capacity, Global)
+    }
+
+    /// Creates an empty vector with zero fixed capacity, without allocating.
+    ///
+    /// Since the vector can never grow, it cannot hold any elements; however, being a `const fn`,
+    /// it can be used in `const` and `static` initializers, for instance as a placeholder to be replaced later.
+    /// It is equivalent to [`FixedVec::default`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_fixed_vec::prelude::*;
+    ///
+    /// static EMPTY: FixedVec<u32> = FixedVec::empty();
+    ///
+    /// assert!(EMPTY.is_empty());
+    /// assert_eq!(EMPTY.capacity(), 0);
+    /// assert!(EMPTY.is_full());
+    /// ```
+    pub const fn empty() -> Self {
         Self {
-            data: Vec::with_capacity(fixed_capacity),
+            data: AllocVec::new(),
+            capacity: 0,
+            generation: 0,
         }
     }
 
+    /// Creates a builder to configure the fixed capacity and the allocator of a new vector,
+    /// and optionally its initial elements.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_fixed_vec::prelude::*;
+    ///
+    /// let vec = FixedVec::builder().capacity(4).from_fn(|i| i * 10);
+    /// assert_eq!(vec, &[0, 10, 20, 30]);
+    /// ```
+    pub fn builder() -> FixedVecBuilder<T> {
+        FixedVecBuilder::new()
+    }
+
+    /// Creates a fixed vector from the given standard vector such that the
+    /// fixed capacity is exactly equal to the length of the vector.
+    ///
+    /// Unlike the `From<Vec<T>>` conversion which keeps the capacity of the vector
+    /// as the fixed capacity, this method shrinks the allocation to fit the elements
+    /// whenever the vector has excess capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_fixed_vec::prelude::*;
+    ///
+    /// let mut vec = Vec::with_capacity(16);
+    /// vec.extend_from_slice(&[0, 1, 2]);
+    ///
+    /// let fixed_vec = FixedVec::from_vec_exact(vec);
+    /// assert_eq!(fixed_vec, &[0, 1, 2]);
+    /// assert_eq!(fixed_vec.capacity(), 3);
+    /// assert!(fixed_vec.is_full());
+    /// ```
+    pub fn from_vec_exact(vec: Vec<T>) -> Self {
+        // conversion to boxed slice guarantees capacity == len
+        Self::from_allocator_vec(from_std_vec(vec.into_boxed_slice().into_vec()))
+    }
+
+    /// Collects the elements of the iterator into a fixed vector such that the
+    /// fixed capacity is exactly equal to the number of collected elements.
+    ///
+    /// Note that the capacity of the vector created by `collect` through the `FromIterator`
+    /// implementation is not deterministic since it depends on the size hint of the iterator.
+    /// This method can be used instead whenever the exact capacity is required.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_fixed_vec::prelude::*;
+    ///
+    /// let fixed_vec = FixedVec::collect_exact((0..100).filter(|x| x % 2 == 0));
+    /// assert_eq!(fixed_vec.len(), 50);
+    /// assert_eq!(fixed_vec.capacity(), 50);
+    /// ```
+    pub fn collect_exact<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        Self::from_vec_exact(iter.into_iter().collect())
+    }
+
+    /// Creates a fixed vector with the given `fixed_capacity` and pushes the elements of the `iter` to it.
+    ///
+    /// * Returns Ok of the vector if all elements of the iterator fit in the fixed capacity.
+    /// * Otherwise, returns a [`FixedVecFullError`] which hands back an iterator over the rejected elements:
+    ///   the first element that did not fit followed by the remaining elements of `iter`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_fixed_vec::prelude::*;
+    ///
+    /// let fixed_vec = FixedVec::from_iter_with_capacity(10, 0..4).unwrap();
+    /// assert_eq!(fixed_vec, &[0, 1, 2, 3]);
+    /// assert_eq!(fixed_vec.capacity(), 10);
+    ///
+    /// let error = FixedVec::from_iter_with_capacity(3, 0..5).unwrap_err();
+    /// assert_eq!(error.capacity(), 3);
+    /// assert_eq!(error.into_rejected().collect::<Vec<_>>(), &[3, 4]);
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn from_iter_with_capacity<I>(
+        fixed_capacity: usize,
+        iter: I,
+    ) -> Result<Self, FixedVecFullError<Chain<Once<T>, I::IntoIter>>>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut vec = Self::new(fixed_capacity);
+        vec.try_extend(iter).map(|_| vec)
+    }
+
+    /// Creates a fixed vector with the given `fixed_capacity` which is filled up to its capacity
+    /// such that the element at position `i` is `f(i)`.
+    ///
+    /// Elements are written directly into the allocated buffer and the length is set once;
+    /// hence, this is more efficient than pushing the elements one by one, each of which checks the available room.
+    /// If `f` panics, the elements created so far are dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the required memory exceeds `isize::MAX` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_fixed_vec::prelude::*;
+    ///
+    /// let vec = FixedVec::from_fn(5, |i| i * i);
+    /// assert_eq!(vec, &[0, 1, 4, 9, 16]);
+    /// assert!(vec.is_full());
+    /// ```
+    pub fn from_fn<F>(fixed_capacity: usize, f: F) -> Self
+    where
+        F: FnMut(usize) -> T,
+    {
+        let mut vec = Self::new(fixed_capacity);
+        vec.fill_room_with(f);
+        vec
+    }
+
+    /// Creates a fixed vector with the given `fixed_capacity` which is filled up to its capacity
+    /// with elements created by repeatedly calling `f`.
+    ///
+    /// Elements are written directly into the allocated buffer and the length is set once.
+    /// If `f` panics, the elements created so far are dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the required memory exceeds `isize::MAX` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_fixed_vec::prelude::*;
+    ///
+    /// let mut next = 1;
+    /// let vec = FixedVec::repeat_with(4, || {
+    ///     next *= 2;
+    ///     next
+    /// });
+    /// assert_eq!(vec, &[2, 4, 8, 16]);
+    /// ```
+    pub fn repeat_with<F>(fixed_capacity: usize, mut f: F) -> Self
+    where
+        F: FnMut() -> T,
+    {
+        Self::from_fn(fixed_capacity, |_| f())
+    }
+
+    /// Creates a fixed vector with the given `fixed_capacity` which is filled up to its capacity
+    /// with clones of the `value`.
+    ///
+    /// Elements are written directly into the allocated buffer and the length is set once.
+    /// The `value` itself is moved into the last position rather than being cloned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the required memory exceeds `isize::MAX` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_fixed_vec::prelude::*;
+    ///
+    /// let vec = FixedVec::filled(3, String::from("x"));
+    /// assert_eq!(vec, ["x", "x", "x"].map(String::from));
+    /// assert!(vec.is_full());
+    ///
+    /// let vec = FixedVec::filled(0, String::from("x"));
+    /// assert!(vec.is_empty());
+    /// ```
+    pub fn filled(fixed_capacity: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        let mut vec = Self::new(fixed_capacity);
+        if let Some(num_clones) = fixed_capacity.checked_sub(1) {
+            vec.fill_room_with(|i| match i < num_clones {
+                true => value.clone(),
+                // SAFETY: the closure is called exactly once with the last position
+                false => unsafe { core::ptr::read(&value) },
+            });
+            core::mem::forget(value);
+        }
+        vec
+    }
+
     /// Returns the fixed vector into inner standard vector.
     ///
     /// # Examples
@@ -57,62 +301,707 @@ impl<T> FixedVec<T> {
     /// let fixed_vec: FixedVec<_> = vec.into();
     /// assert_eq!(fixed_vec.as_slice(), &['a', 'b']);
     /// ```
-    pub fn into_inner(self) -> Vec<T> {
-        self.data
+    pub fn into_inner(self) -> Vec<T> {
+        into_std_vec(self.data)
+    }
+}
+
+impl<T, A: Allocator> FixedVec<T, A> {
+    /// Creates a new vector with the given fixed capacity, allocated by the given allocator.
+    ///
+    /// Note that the vector can never grow beyond this capacity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the required memory exceeds `isize::MAX` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #![cfg_attr(feature = "nightly", feature(allocator_api))]
+    /// use orx_fixed_vec::prelude::*;
+    /// use orx_fixed_vec::Global;
+    ///
+    /// let mut vec = FixedVec::new_in(7, Global);
+    /// vec.push(42);
+    ///
+    /// assert_eq!(7, vec.capacity());
+    /// ```
+    pub fn new_in(fixed_capacity: usize, alloc: A) -> Self {
+        Self::from_parts(
+            AllocVec::with_capacity_in(fixed_capacity, alloc),
+            fixed_capacity,
+        )
+    }
+
+    /// Creates a fixed vector from the given allocator-aware vector without any allocation.
+    ///
+    /// Fixed capacity of the created vector is equal to the capacity of the vector,
+    /// which might be greater than its length.
+    /// For zero-sized types, fixed capacity is equal to the length of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #![cfg_attr(feature = "nightly", feature(allocator_api))]
+    /// use orx_fixed_vec::prelude::*;
+    /// use orx_fixed_vec::Global;
+    /// use allocator_api2::vec::Vec;
+    ///
+    /// let mut vec = Vec::with_capacity_in(16, Global);
+    /// vec.extend_from_slice(&[0, 1, 2]);
+    ///
+    /// let fixed_vec = FixedVec::from_allocator_vec(vec);
+    /// assert_eq!(fixed_vec, &[0, 1, 2]);
+    /// assert_eq!(fixed_vec.capacity(), 16);
+    /// ```
+    pub fn from_allocator_vec(vec: AllocVec<T, A>) -> Self {
+        let fixed_capacity = match is_zst::<T>() {
+            true => vec.len(),
+            false => vec.capacity(),
+        };
+        Self::from_parts(vec, fixed_capacity)
+    }
+
+    /// Creates the fixed vector with the given `fixed_capacity`, which must be within `data.len()..=data.capacity()`.
+    pub(crate) fn from_parts(data: AllocVec<T, A>, fixed_capacity: usize) -> Self {
+        debug_assert!(data.len() <= fixed_capacity && fixed_capacity <= data.capacity());
+        Self {
+            data,
+            capacity: fixed_capacity,
+            generation: 0,
+        }
+    }
+
+    /// Returns the fixed vector into the inner allocator-aware vector.
+    ///
+    /// [`FixedVec::into_inner`] can be used to obtain a standard vector when the vector uses the global allocator.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #![cfg_attr(feature = "nightly", feature(allocator_api))]
+    /// use orx_fixed_vec::prelude::*;
+    /// use orx_fixed_vec::Global;
+    ///
+    /// let mut fixed_vec = FixedVec::new_in(8, Global);
+    /// fixed_vec.push('a');
+    ///
+    /// let vec = fixed_vec.into_allocator_vec();
+    /// assert_eq!(vec.as_slice(), &['a']);
+    /// assert_eq!(vec.capacity(), 8);
+    /// ```
+    pub fn into_allocator_vec(self) -> AllocVec<T, A> {
+        self.data
+    }
+
+    /// Returns a reference to the allocator of the vector.
+    pub fn allocator(&self) -> &A {
+        self.data.allocator()
+    }
+
+    /// Returns the available room for new items; i.e.,
+    /// `capacity() - len()`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_fixed_vec::prelude::*;
+    ///
+    /// let mut vec = FixedVec::new(7);
+    /// vec.push(42);
+    ///
+    /// assert_eq!(7, vec.capacity());
+    /// assert_eq!(1, vec.len());
+    /// assert_eq!(6, vec.room());
+    /// ```
+    pub fn room(&self) -> usize {
+        self.capacity - self.data.len()
+    }
+
+    /// Return whether the fixed vector is full or not;
+    /// equivalent to `capacity() == len()` or `room() == 0`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_fixed_vec::prelude::*;
+    ///
+    /// let mut vec = FixedVec::new(2);
+    /// assert!(!vec.is_full());
+    ///
+    /// vec.push(42);
+    /// assert!(!vec.is_full());
+    ///
+    /// vec.push(7);
+    /// assert!(vec.is_full());
+    /// ```
+    pub fn is_full(&self) -> bool {
+        self.capacity == self.data.len()
+    }
+
+    /// Extracts a slice containing the entire vector.
+    ///
+    /// Equivalent to &s[..].
+    pub fn as_slice(&self) -> &[T] {
+        self.data.as_slice()
+    }
+
+    /// Appends an element to the back of the vector if there is available room;
+    /// returns back the `value` in a [`FixedVecFullError`] otherwise.
+    ///
+    /// This is the non-panicking counterpart of `push`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_fixed_vec::prelude::*;
+    ///
+    /// let mut vec = FixedVec::new(2);
+    ///
+    /// assert!(vec.try_push(0).is_ok());
+    /// assert!(vec.try_push(1).is_ok());
+    ///
+    /// let error = vec.try_push(2).unwrap_err();
+    /// assert_eq!(error.capacity(), 2);
+    /// assert_eq!(error.len(), 2);
+    /// assert_eq!(error.into_rejected(), 2);
+    ///
+    /// assert_eq!(vec, &[0, 1]);
+    /// ```
+    pub fn try_push(&mut self, value: T) -> Result<(), FixedVecFullError<T>> {
+        match self.is_full() {
+            true => Err(self.full_error(value)),
+            false => {
+                self.data.push(value);
+                Ok(())
+            }
+        }
+    }
+
+    /// Inserts an element at position `index` within the vector, shifting all elements after it to the right,
+    /// if there is available room; returns back the `element` in a [`FixedVecFullError`] otherwise.
+    ///
+    /// This is the non-panicking counterpart of `insert` with respect to capacity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len` while the vector has available room.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_fixed_vec::prelude::*;
+    ///
+    /// let mut vec = FixedVec::new(3);
+    /// vec.extend_from_slice(&[0, 2]);
+    ///
+    /// assert!(vec.try_insert(1, 1).is_ok());
+    /// assert_eq!(vec, &[0, 1, 2]);
+    ///
+    /// let error = vec.try_insert(0, 42).unwrap_err();
+    /// assert_eq!(error.into_rejected(), 42);
+    /// assert_eq!(vec, &[0, 1, 2]);
+    /// ```
+    pub fn try_insert(&mut self, index: usize, element: T) -> Result<(), FixedVecFullError<T>> {
+        match self.is_full() {
+            true => Err(self.full_error(element)),
+            false => {
+                if index < self.data.len() {
+                    self.invalidate_indices();
+                }
+                self.data.insert(index, element);
+                Ok(())
+            }
+        }
+    }
+
+    /// Clones and appends all elements in the slice `other` to the vector if there is enough room for all of them;
+    /// returns back the `other` slice in a [`FixedVecFullError`] otherwise.
+    ///
+    /// The operation is all or nothing; the vector is not mutated in the error case.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_fixed_vec::prelude::*;
+    ///
+    /// let mut vec = FixedVec::new(4);
+    ///
+    /// assert!(vec.try_extend_from_slice(&[0, 1, 2]).is_ok());
+    ///
+    /// let error = vec.try_extend_from_slice(&[3, 4]).unwrap_err();
+    /// assert_eq!(error.room(), 1);
+    /// assert_eq!(error.into_rejected(), &[3, 4]);
+    ///
+    /// assert_eq!(vec, &[0, 1, 2]);
+    /// ```
+    pub fn try_extend_from_slice<'a>(
+        &mut self,
+        other: &'a [T],
+    ) -> Result<(), FixedVecFullError<&'a [T]>>
+    where
+        T: Clone,
+    {
+        match other.len() <= self.room() {
+            true => {
+                self.data.extend_from_slice(other);
+                Ok(())
+            }
+            false => Err(self.full_error(other)),
+        }
+    }
+
+    /// Appends elements of the `iter` to the vector until either the iterator is consumed or the vector is full.
+    ///
+    /// * Returns Ok if all elements of the iterator could be added to the vector.
+    /// * Otherwise, returns a [`FixedVecFullError`] which hands back an iterator over the rejected elements:
+    ///   the first element that did not fit followed by the remaining elements of `iter`.
+    ///
+    /// Note that the elements added before the vector became full remain in the vector in the error case.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_fixed_vec::prelude::*;
+    ///
+    /// let mut vec = FixedVec::new(4);
+    ///
+    /// assert!(vec.try_extend(0..2).is_ok());
+    ///
+    /// let error = vec.try_extend(2..7).unwrap_err();
+    /// assert_eq!(error.len(), 4);
+    /// let rejected: Vec<_> = error.into_rejected().collect();
+    /// assert_eq!(rejected, &[4, 5, 6]);
+    ///
+    /// assert_eq!(vec, &[0, 1, 2, 3]);
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn try_extend<I>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), FixedVecFullError<Chain<Once<T>, I::IntoIter>>>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut iter = iter.into_iter();
+        while let Some(value) = iter.next() {
+            if let Err(error) = self.try_push(value) {
+                let rejected = once(error.into_rejected()).chain(iter);
+                return Err(self.full_error(rejected));
+            }
+        }
+        Ok(())
+    }
+
+    /// Pushes elements of the `iter` to the vector until the vector is full or the iterator is consumed,
+    /// and returns the iterator over the remaining elements which did not fit.
+    ///
+    /// An element is pulled from the iterator only when there is room for it; therefore,
+    /// the returned iterator starts with the first element that did not fit.
+    /// This allows to fill the vector from an unbounded stream without counting its elements in advance.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_fixed_vec::prelude::*;
+    ///
+    /// let mut vec = FixedVec::new(4);
+    /// vec.push(0);
+    ///
+    /// let mut remaining = vec.extend_bounded(1..);
+    /// assert_eq!(vec, &[0, 1, 2, 3]);
+    /// assert!(vec.is_full());
+    /// assert_eq!(remaining.next(), Some(4));
+    ///
+    /// let mut vec = FixedVec::new(4);
+    /// let mut remaining = vec.extend_bounded(0..2);
+    /// assert_eq!(vec, &[0, 1]);
+    /// assert_eq!(remaining.next(), None);
+    /// ```
+    pub fn extend_bounded<I>(&mut self, iter: I) -> I::IntoIter
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut iter = iter.into_iter();
+        while !self.is_full() {
+            match iter.next() {
+                Some(value) => self.data.push(value),
+                None => break,
+            }
+        }
+        iter
+    }
+
+    /// Removes the elements within the given `range` from the vector in bulk,
+    /// and returns all removed elements as an iterator.
+    ///
+    /// # Pinned elements
+    ///
+    /// * Elements before `range.start` keep their memory locations.
+    /// * Elements after the range are shifted to the left to fill the gap; hence, their addresses change.
+    ///
+    /// The capacity of the vector is not affected.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point or if the end point is greater than the length of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_fixed_vec::prelude::*;
+    ///
+    /// let mut vec: FixedVec<_> = FixedVec::new(8);
+    /// vec.extend_from_slice(&[0, 1, 2, 3, 4, 5]);
+    /// let addr1 = &vec[1] as *const i32;
+    ///
+    /// let drained: Vec<_> = vec.drain(2..4).collect();
+    /// assert_eq!(drained, &[2, 3]);
+    /// assert_eq!(vec, &[0, 1, 4, 5]);
+    /// assert_eq!(vec.capacity(), 8);
+    ///
+    /// // prefix before the drained range stays pinned
+    /// assert_eq!(addr1, &vec[1] as *const i32);
+    /// ```
+    pub fn drain<R>(&mut self, range: R) -> Drain<'_, T, A>
+    where
+        R: RangeBounds<usize>,
+    {
+        if range_start(&range) < range_end(&range, self.data.len()) {
+            self.invalidate_indices();
+        }
+        self.data.drain(range)
+    }
+
+    /// Retains only the elements specified by the predicate `f`;
+    /// i.e., removes all elements `e` for which `f(&e)` returns false.
+    ///
+    /// # Pinned elements
+    ///
+    /// * Elements before the first removed element keep their memory locations.
+    /// * Retained elements after the first removed element are shifted to the left; hence, their addresses change.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_fixed_vec::prelude::*;
+    ///
+    /// let mut vec: FixedVec<_> = FixedVec::new(8);
+    /// vec.extend_from_slice(&[0, 1, 2, 3, 4, 5]);
+    /// let addr1 = &vec[1] as *const i32;
+    ///
+    /// vec.retain(|x| *x != 2 && *x != 4);
+    /// assert_eq!(vec, &[0, 1, 3, 5]);
+    ///
+    /// // prefix before the first removed element stays pinned
+    /// assert_eq!(addr1, &vec[1] as *const i32);
+    /// ```
+    pub fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let len = self.data.len();
+        self.data.retain(f);
+        self.invalidate_indices_if_shorter_than(len);
+    }
+
+    /// Retains only the elements specified by the predicate `f`, passing a mutable reference to it;
+    /// i.e., removes all elements `e` for which `f(&mut e)` returns false.
+    ///
+    /// # Pinned elements
+    ///
+    /// * Elements before the first removed element keep their memory locations.
+    /// * Retained elements after the first removed element are shifted to the left; hence, their addresses change.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_fixed_vec::prelude::*;
+    ///
+    /// let mut vec: FixedVec<_> = FixedVec::new(8);
+    /// vec.extend_from_slice(&[0, 1, 2, 3, 4, 5]);
+    ///
+    /// vec.retain_mut(|x| {
+    ///     *x *= 10;
+    ///     *x < 30
+    /// });
+    /// assert_eq!(vec, &[0, 10, 20]);
+    /// ```
+    pub fn retain_mut<F>(&mut self, f: F)
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        let len = self.data.len();
+        self.data.retain_mut(f);
+        self.invalidate_indices_if_shorter_than(len);
+    }
+
+    /// Removes all but the first of consecutive elements in the vector that resolve to the same key.
+    ///
+    /// # Pinned elements
+    ///
+    /// * Elements before the first removed duplicate keep their memory locations.
+    /// * Retained elements after the first removed duplicate are shifted to the left; hence, their addresses change.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_fixed_vec::prelude::*;
+    ///
+    /// let mut vec: FixedVec<_> = FixedVec::new(8);
+    /// vec.extend_from_slice(&[10, 20, 21, 30, 20]);
+    /// let addr1 = &vec[1] as *const i32;
+    ///
+    /// vec.dedup_by_key(|x| *x / 10);
+    /// assert_eq!(vec, &[10, 20, 30, 20]);
+    ///
+    /// // prefix up to and including the retained element of the first duplicate run stays pinned
+    /// assert_eq!(addr1, &vec[1] as *const i32);
+    /// ```
+    pub fn dedup_by_key<F, K>(&mut self, key: F)
+    where
+        F: FnMut(&mut T) -> K,
+        K: PartialEq,
+    {
+        let len = self.data.len();
+        self.data.dedup_by_key(key);
+        self.invalidate_indices_if_shorter_than(len);
+    }
+
+    /// Splits the vector into two at the given index.
+    ///
+    /// Returns a newly allocated vector containing the elements in the range `[at, len)`,
+    /// with a fixed capacity equal to the number of these elements.
+    /// After the call, the original vector will be left containing the elements `[0, at)`
+    /// with its fixed capacity unchanged.
+    ///
+    /// # Pinned elements
+    ///
+    /// * Elements in `[0, at)` remaining in this vector keep their memory locations.
+    /// * Elements in `[at, len)` are moved to the new allocation of the returned vector; hence, their addresses change.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at > len`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_fixed_vec::prelude::*;
+    ///
+    /// let mut vec: FixedVec<_> = FixedVec::new(8);
+    /// vec.extend_from_slice(&[0, 1, 2, 3, 4]);
+    /// let addr1 = &vec[1] as *const i32;
+    ///
+    /// let other = vec.split_off(2);
+    /// assert_eq!(vec, &[0, 1]);
+    /// assert_eq!(vec.capacity(), 8);
+    /// assert_eq!(other, &[2, 3, 4]);
+    /// assert_eq!(other.capacity(), 3);
+    ///
+    /// assert_eq!(addr1, &vec[1] as *const i32);
+    /// ```
+    pub fn split_off(&mut self, at: usize) -> Self
+    where
+        A: Clone,
+    {
+        let len = self.data.len();
+        assert!(
+            at <= len,
+            "`at` split index (is {at}) should be <= len (is {len})"
+        );
+
+        let mut other = AllocVec::with_capacity_in(len - at, self.allocator().clone());
+        other.extend(self.data.drain(at..));
+        self.invalidate_indices_if_shorter_than(len);
+        Self::from_parts(other, len - at)
+    }
+
+    /// Removes the element at the given `index` and returns it, replacing it with the last element of the vector.
+    ///
+    /// This does not preserve the order of the remaining elements, but it is O(1).
+    /// Together with the removed element, the relocation of the last element is returned as `Some((from, to))`
+    /// whenever it is moved; it is None if the removed element is the last element.
+    ///
+    /// # Pinned elements
+    ///
+    /// * All elements other than the last element keep their memory locations.
+    /// * The last element is moved from position `from` to position `to`, which is the reported relocation.
+    ///
+    /// Therefore, self-referential structures only need to patch the references to the relocated element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_fixed_vec::prelude::*;
+    ///
+    /// let mut vec: FixedVec<_> = FixedVec::new(8);
+    /// vec.extend_from_slice(&['a', 'b', 'c', 'd']);
+    /// let addr_c = &vec[2] as *const char;
+    ///
+    /// let (removed, relocation) = vec.swap_remove(1);
+    /// assert_eq!(removed, 'b');
+    /// assert_eq!(relocation, Some((3, 1)));
+    /// assert_eq!(vec, &['a', 'd', 'c']);
+    /// assert_eq!(addr_c, &vec[2] as *const char);
+    ///
+    /// let (removed, relocation) = vec.swap_remove(2);
+    /// assert_eq!(removed, 'c');
+    /// assert_eq!(relocation, None);
+    /// assert_eq!(vec, &['a', 'd']);
+    /// ```
+    pub fn swap_remove(&mut self, index: usize) -> (T, Option<(usize, usize)>) {
+        let removed = self.data.swap_remove(index);
+        let last = self.data.len();
+        let relocation = match index < last {
+            true => {
+                self.invalidate_indices();
+                Some((last, index))
+            }
+            false => None,
+        };
+        (removed, relocation)
     }
 
-    /// Returns the available room for new items; i.e.,
-    /// `capacity() - len()`.
+    /// Removes the elements at the given `indices` and returns them in the order of increasing index,
+    /// together with the relocations of the elements which are moved to fill the gaps.
+    ///
+    /// Let `n = indices.len()` and `new_len = len - n`.
+    /// Each removed position below `new_len` is filled by one of the remaining elements at a position `>= new_len`,
+    /// starting by the last element. This does not preserve the order of the remaining elements,
+    /// but each element is moved at most once.
+    ///
+    /// Relocations are reported as `(from, to)` pairs of positions, in the order of increasing `to`.
+    ///
+    /// # Pinned elements
+    ///
+    /// * All elements other than the relocated elements keep their memory locations.
+    /// * Each relocated element is moved exactly once, from position `from` to position `to`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the indices is out of bounds, or if `indices` contains duplicates.
     ///
     /// # Examples
     ///
     /// ```rust
     /// use orx_fixed_vec::prelude::*;
     ///
-    /// let mut vec = FixedVec::new(7);
-    /// vec.push(42);
+    /// let mut vec: FixedVec<_> = FixedVec::new(8);
+    /// vec.extend_from_slice(&[0, 1, 2, 3, 4, 5, 6]);
+    /// let addr2 = &vec[2] as *const i32;
     ///
-    /// assert_eq!(7, vec.capacity());
-    /// assert_eq!(1, vec.len());
-    /// assert_eq!(6, vec.room());
+    /// let (removed, relocations) = vec.swap_remove_many(&[5, 1, 3]);
+    /// assert_eq!(removed, &[1, 3, 5]);
+    /// assert_eq!(relocations, &[(6, 1), (4, 3)]);
+    /// assert_eq!(vec, &[0, 6, 2, 4]);
+    ///
+    /// assert_eq!(addr2, &vec[2] as *const i32);
     /// ```
-    pub fn room(&self) -> usize {
-        self.data.capacity() - self.data.len()
+    #[allow(clippy::type_complexity)]
+    pub fn swap_remove_many(&mut self, indices: &[usize]) -> (Vec<T>, Vec<(usize, usize)>) {
+        let len = self.data.len();
+        let mut removed_indices = indices.to_vec();
+        removed_indices.sort_unstable();
+        assert!(
+            removed_indices.windows(2).all(|w| w[0] < w[1]),
+            "{}",
+            ERR_MSG_DUPLICATE_INDICES
+        );
+        if let Some(&max) = removed_indices.last() {
+            assert!(
+                max < len,
+                "swap_remove_many index (is {max}) should be < len (is {len})"
+            );
+        }
+
+        let new_len = len - removed_indices.len();
+        let is_removed = |i: &usize| removed_indices.binary_search(i).is_ok();
+
+        // removed positions in the remaining part are filled by the remaining elements of the tail
+        let holes = removed_indices.iter().copied().take_while(|i| *i < new_len);
+        let movers = (new_len..len).rev().filter(|i| !is_removed(i));
+        let relocations: Vec<_> = movers.zip(holes).collect();
+
+        // original position of each element in the tail after the relocations
+        let mut tail_origins: Vec<_> = (new_len..len).collect();
+        for &(from, to) in &relocations {
+            self.data.swap(from, to);
+            tail_origins[from - new_len] = to;
+        }
+
+        if !relocations.is_empty() {
+            self.invalidate_indices();
+        }
+
+        let mut removed: Vec<_> = tail_origins
+            .into_iter()
+            .zip(self.data.drain(new_len..))
+            .collect();
+        removed.sort_unstable_by_key(|(origin, _)| *origin);
+        let removed = removed.into_iter().map(|(_, value)| value).collect();
+
+        (removed, relocations)
     }
 
-    /// Return whether the fixed vector is full or not;
-    /// equivalent to `capacity() == len()` or `room() == 0`.
+    /// Moves all the elements of `other` into this vector, leaving `other` empty.
+    ///
+    /// Unlike the standard vector, this vector never reallocates; therefore, `other`
+    /// must fit in the available room of this vector.
+    ///
+    /// # Pinned elements
+    ///
+    /// * All elements of this vector keep their memory locations.
+    /// * Elements of `other` are moved to the end of this vector; hence, their addresses change.
+    ///
+    /// Capacity of `other` is not affected.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is not enough room for the elements of `other`; i.e., if `other.len() > self.room()`.
     ///
     /// # Examples
     ///
     /// ```rust
     /// use orx_fixed_vec::prelude::*;
     ///
-    /// let mut vec = FixedVec::new(2);
-    /// assert!(!vec.is_full());
+    /// let mut vec: FixedVec<_> = FixedVec::new(5);
+    /// vec.extend_from_slice(&[0, 1]);
+    /// let addr1 = &vec[1] as *const i32;
     ///
-    /// vec.push(42);
-    /// assert!(!vec.is_full());
+    /// let mut other: FixedVec<_> = FixedVec::new(3);
+    /// other.extend_from_slice(&[2, 3, 4]);
     ///
-    /// vec.push(7);
-    /// assert!(vec.is_full());
+    /// vec.append(&mut other);
+    /// assert_eq!(vec, &[0, 1, 2, 3, 4]);
+    /// assert!(other.is_empty());
+    /// assert_eq!(other.capacity(), 3);
+    ///
+    /// assert_eq!(addr1, &vec[1] as *const i32);
     /// ```
-    pub fn is_full(&self) -> bool {
-        self.data.capacity() == self.data.len()
+    pub fn append(&mut self, other: &mut Self) {
+        self.panic_if_not_enough_room_for(other.data.len());
+        if !other.data.is_empty() {
+            other.invalidate_indices();
+        }
+        self.data.append(&mut other.data);
     }
 
-    /// Extracts a slice containing the entire vector.
-    ///
-    /// Equivalent to &s[..].
-    pub fn as_slice(&self) -> &[T] {
-        self.data.as_slice()
+    // helpers
+    #[inline(always)]
+    fn full_error<R>(&self, rejected: R) -> FixedVecFullError<R> {
+        FixedVecFullError::new(rejected, self.capacity, self.data.len())
     }
 
-    // helpers
     #[inline(always)]
     pub(crate) fn panic_if_not_enough_room_for(&self, num_new_items: usize) {
         assert!(
-            self.data.len() + num_new_items <= self.data.capacity(),
+            self.data.len() + num_new_items <= self.capacity,
             "{}",
             ERR_MSG_OUT_OF_ROOM
         );
@@ -120,35 +1009,74 @@ impl<T> FixedVec<T> {
 
     #[inline(always)]
     pub(crate) fn push_or_panic(&mut self, value: T) {
-        assert!(
-            self.data.len() < self.data.capacity(),
-            "{}",
-            ERR_MSG_OUT_OF_ROOM
-        );
+        assert!(self.data.len() < self.capacity, "{}", ERR_MSG_OUT_OF_ROOM);
         self.data.push(value);
     }
+
+    /// Fills the remaining room of the vector such that the element at position `i` is `f(i)`.
+    ///
+    /// Elements are written directly into the spare capacity and the length is set once,
+    /// when the guard is dropped; which also happens if `f` panics so that created elements are not leaked.
+    pub(crate) fn fill_room_with<F>(&mut self, mut f: F)
+    where
+        F: FnMut(usize) -> T,
+    {
+        let (begin, end) = (self.data.len(), self.capacity);
+        let mut guard = SetLenOnDrop {
+            vec: &mut self.data,
+            len: begin,
+        };
+        let ptr = guard.vec.as_mut_ptr();
+        for i in begin..end {
+            // SAFETY: i < capacity, and the position is not initialized
+            unsafe { ptr.add(i).write(f(i)) };
+            guard.len += 1;
+        }
+    }
+}
+
+/// Sets the length of the vector to `len` when dropped.
+struct SetLenOnDrop<'a, T, A: Allocator> {
+    vec: &'a mut AllocVec<T, A>,
+    len: usize,
+}
+
+impl<T, A: Allocator> Drop for SetLenOnDrop<'_, T, A> {
+    fn drop(&mut self) {
+        // SAFETY: positions up to len are initialized by the owner of the guard
+        unsafe { self.vec.set_len(self.len) };
+    }
 }
 
 impl<T> From<Vec<T>> for FixedVec<T> {
+    /// Converts the standard vector into a fixed vector without any allocation.
+    ///
+    /// Fixed capacity of the created vector is equal to the capacity of the standard vector,
+    /// which might be greater than its length.
+    /// [`FixedVec::from_vec_exact`] can be used to create a fixed vector with capacity equal to the length.
     fn from(data: Vec<T>) -> Self {
-        Self { data }
+        Self::from_allocator_vec(from_std_vec(data))
     }
 }
 
 impl<T> From<FixedVec<T>> for Vec<T> {
     fn from(value: FixedVec<T>) -> Self {
-        value.data
+        value.into_inner()
     }
 }
 
 const ERR_MSG_OUT_OF_ROOM: &str =
     "FixedVec is full, a fixed capacity vector cannot exceed its initial capacity.";
 
+const ERR_MSG_DUPLICATE_INDICES: &str = "indices to remove must be distinct.";
+
 #[cfg(test)]
 mod tests {
+    use alloc::string::{String, ToString};
     use alloc::vec;
     use alloc::vec::Vec;
 
+    use crate::helpers::drop_counter::DropCounter;
     use crate::prelude::*;
 
     #[test]
@@ -159,6 +1087,69 @@ mod tests {
         assert_eq!(17, vec.capacity());
     }
 
+    #[test]
+    #[should_panic]
+    fn new_capacity_overflow() {
+        let _ = FixedVec::<u64>::new(usize::MAX / 4);
+    }
+
+    #[test]
+    fn new_zero_sized() {
+        let vec = FixedVec::<()>::new(usize::MAX);
+        assert_eq!(usize::MAX, vec.capacity());
+        assert_eq!(usize::MAX, vec.room());
+    }
+
+    #[test]
+    fn from_fn() {
+        let vec = FixedVec::from_fn(4, |i| i.to_string());
+        assert_eq!(vec, ["0", "1", "2", "3"].map(String::from));
+        assert_eq!(vec.capacity(), 4);
+
+        let vec = FixedVec::from_fn(0, |i| i);
+        assert!(vec.is_empty());
+        assert!(vec.is_full());
+
+        let vec = FixedVec::from_fn(1000, |_| ());
+        assert_eq!(vec.len(), 1000);
+        assert_eq!(vec.capacity(), 1000);
+    }
+
+    #[test]
+    fn repeat_with() {
+        let mut values = ['a', 'b', 'c'].into_iter().cycle();
+        let vec = FixedVec::repeat_with(5, || values.next().expect("is-cyclic"));
+        assert_eq!(vec, &['a', 'b', 'c', 'a', 'b']);
+        assert!(vec.is_full());
+    }
+
+    #[test]
+    fn filled() {
+        let vec = FixedVec::filled(3, vec![1, 2]);
+        assert_eq!(vec, [vec![1, 2], vec![1, 2], vec![1, 2]]);
+        assert!(vec.is_full());
+
+        let vec = FixedVec::filled(1, 7.to_string());
+        assert_eq!(vec, &[7.to_string()]);
+
+        let vec = FixedVec::filled(0, 7.to_string());
+        assert!(vec.is_empty());
+    }
+
+    #[test]
+    fn from_fn_panic_drops_created_elements() {
+        let drops = DropCounter::new();
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            FixedVec::from_fn(5, |i| {
+                assert!(i < 3);
+                drops.track()
+            })
+        }));
+        assert!(result.is_err());
+        assert_eq!(drops.dropped(), 3);
+        drops.assert_no_leak();
+    }
+
     #[test]
     fn from() {
         let vec = vec![1, 3, 42];
@@ -177,6 +1168,52 @@ mod tests {
         assert_eq!(&vec, &into_vec);
     }
 
+    #[test]
+    fn from_vec_exact() {
+        let mut vec = Vec::with_capacity(7);
+        vec.extend_from_slice(&[1, 3, 42]);
+        let fixed_vec = FixedVec::from_vec_exact(vec);
+        assert_eq!(fixed_vec, &[1, 3, 42]);
+        assert_eq!(3, fixed_vec.capacity());
+        assert_eq!(0, fixed_vec.room());
+
+        let fixed_vec = FixedVec::from_vec_exact(Vec::<char>::with_capacity(7));
+        assert_eq!(0, fixed_vec.capacity());
+    }
+
+    #[test]
+    fn collect_exact() {
+        let fixed_vec = FixedVec::collect_exact(0..20);
+        assert_eq!(fixed_vec, (0..20).collect::<Vec<_>>());
+        assert_eq!(20, fixed_vec.capacity());
+
+        let fixed_vec = FixedVec::collect_exact((0..20).filter(|x| x % 3 == 0));
+        assert_eq!(fixed_vec, &[0, 3, 6, 9, 12, 15, 18]);
+        assert_eq!(7, fixed_vec.capacity());
+
+        let fixed_vec = FixedVec::collect_exact((0..20).filter(|x| *x > 100));
+        assert_eq!(0, fixed_vec.capacity());
+    }
+
+    #[test]
+    fn from_iter_with_capacity() {
+        let fixed_vec = FixedVec::from_iter_with_capacity(20, 0..20).expect("has-room");
+        assert_eq!(fixed_vec, (0..20).collect::<Vec<_>>());
+        assert_eq!(20, fixed_vec.capacity());
+
+        let fixed_vec = FixedVec::from_iter_with_capacity(30, 0..20).expect("has-room");
+        assert_eq!(fixed_vec, (0..20).collect::<Vec<_>>());
+        assert_eq!(30, fixed_vec.capacity());
+
+        let error = FixedVec::from_iter_with_capacity(15, 0..20).expect_err("no-room");
+        assert_eq!(error.capacity(), 15);
+        assert_eq!(error.len(), 15);
+        assert_eq!(
+            error.into_rejected().collect::<Vec<_>>(),
+            &[15, 16, 17, 18, 19]
+        );
+    }
+
     #[test]
     fn room() {
         let mut vec = FixedVec::new(10);
@@ -252,4 +1289,399 @@ mod tests {
 
         vec.push_or_panic(3);
     }
+
+    #[test]
+    fn try_push() {
+        let mut vec = FixedVec::new(3);
+
+        for i in 0..3 {
+            assert_eq!(Ok(()), vec.try_push(i));
+        }
+
+        let error = vec.try_push(3).expect_err("is-full");
+        assert_eq!(error.capacity(), 3);
+        assert_eq!(error.len(), 3);
+        assert_eq!(error.into_rejected(), 3);
+
+        assert_eq!(vec, &[0, 1, 2]);
+    }
+
+    #[test]
+    fn try_push_zero_capacity() {
+        let mut vec = FixedVec::new(0);
+
+        let error = vec.try_push('x').expect_err("is-full");
+        assert_eq!(error.capacity(), 0);
+        assert!(error.is_empty());
+        assert_eq!(error.into_rejected(), 'x');
+    }
+
+    #[test]
+    fn try_insert() {
+        let mut vec = FixedVec::new(3);
+
+        assert_eq!(Ok(()), vec.try_insert(0, 2));
+        assert_eq!(Ok(()), vec.try_insert(0, 0));
+        assert_eq!(Ok(()), vec.try_insert(1, 1));
+        assert_eq!(vec, &[0, 1, 2]);
+
+        let error = vec.try_insert(1, 42).expect_err("is-full");
+        assert_eq!(error.capacity(), 3);
+        assert_eq!(error.len(), 3);
+        assert_eq!(error.into_rejected(), 42);
+
+        assert_eq!(vec, &[0, 1, 2]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn try_insert_out_of_bounds() {
+        let mut vec = FixedVec::new(3);
+        vec.push(0);
+        let _ = vec.try_insert(2, 1);
+    }
+
+    #[test]
+    fn try_extend_from_slice() {
+        let mut vec = FixedVec::new(5);
+
+        assert_eq!(Ok(()), vec.try_extend_from_slice(&[0, 1]));
+        assert_eq!(Ok(()), vec.try_extend_from_slice(&[]));
+
+        let error = vec
+            .try_extend_from_slice(&[2, 3, 4, 5])
+            .expect_err("no-room");
+        assert_eq!(error.capacity(), 5);
+        assert_eq!(error.len(), 2);
+        assert_eq!(error.room(), 3);
+        assert_eq!(error.into_rejected(), &[2, 3, 4, 5]);
+        assert_eq!(vec, &[0, 1]);
+
+        assert_eq!(Ok(()), vec.try_extend_from_slice(&[2, 3, 4]));
+        assert_eq!(vec, &[0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn try_extend() {
+        let mut vec = FixedVec::new(5);
+
+        assert!(vec.try_extend(0..2).is_ok());
+        assert!(vec.try_extend(Vec::new()).is_ok());
+        assert!(vec.try_extend(2..5).is_ok());
+        assert_eq!(vec, &[0, 1, 2, 3, 4]);
+
+        let error = vec.try_extend(5..8).expect_err("is-full");
+        assert_eq!(error.capacity(), 5);
+        assert_eq!(error.len(), 5);
+        assert_eq!(error.into_rejected().collect::<Vec<_>>(), &[5, 6, 7]);
+
+        vec.clear();
+        let error = vec.try_extend(0..8).expect_err("no-room");
+        assert_eq!(error.into_rejected().collect::<Vec<_>>(), &[5, 6, 7]);
+        assert_eq!(vec, &[0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn extend_bounded() {
+        let mut vec = FixedVec::new(5);
+
+        let mut remaining = vec.extend_bounded(0..3);
+        assert_eq!(remaining.next(), None);
+        assert_eq!(vec, &[0, 1, 2]);
+
+        let mut remaining = vec.extend_bounded(3..);
+        assert_eq!(vec, &[0, 1, 2, 3, 4]);
+        assert_eq!(remaining.next(), Some(5));
+
+        let mut remaining = vec.extend_bounded(10..12);
+        assert_eq!(remaining.next(), Some(10));
+        assert_eq!(vec, &[0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn extend_bounded_does_not_pull_beyond_capacity() {
+        let mut pulled = 0;
+        let stream = core::iter::repeat_with(|| {
+            pulled += 1;
+            pulled
+        });
+
+        let mut vec = FixedVec::new(8);
+        let _ = vec.extend_bounded(stream);
+        assert_eq!(vec, &[1, 2, 3, 4, 5, 6, 7, 8]);
+        assert_eq!(pulled, 8);
+
+        let mut vec = FixedVec::new(0);
+        let mut remaining = vec.extend_bounded(["a".to_string()]);
+        assert_eq!(remaining.next().as_deref(), Some("a"));
+        assert!(vec.is_empty());
+    }
+
+    fn strings(capacity: usize, len: usize) -> FixedVec<String> {
+        let mut vec = FixedVec::new(capacity);
+        for i in 0..len {
+            vec.push(i.to_string());
+        }
+        vec
+    }
+
+    fn addresses<T>(vec: &FixedVec<T>) -> Vec<*const T> {
+        vec.iter().map(|x| x as *const T).collect()
+    }
+
+    /// Asserts that the first `num_pinned` elements are still at their original addresses,
+    /// and that the addresses beyond the new length do not belong to the vector anymore.
+    fn assert_pinned_prefix<T>(vec: &FixedVec<T>, addresses: &[*const T], num_pinned: usize) {
+        for (i, ptr) in addresses.iter().enumerate().take(num_pinned) {
+            // SAFETY: element at i is still alive at its original address
+            let element = unsafe { &**ptr };
+            assert_eq!(vec.index_of(element), Some(i));
+            assert!(vec.contains_reference(element));
+        }
+        for ptr in addresses.iter().skip(vec.len()) {
+            assert!(!vec.contains_ptr(*ptr));
+        }
+    }
+
+    fn assert_values(vec: &FixedVec<String>, values: &[usize]) {
+        assert_eq!(vec.len(), values.len());
+        for (x, y) in vec.iter().zip(values) {
+            assert_eq!(x, &y.to_string());
+        }
+    }
+
+    #[test]
+    fn drain() {
+        let mut vec = strings(12, 10);
+        let addr = addresses(&vec);
+
+        let drained: Vec<_> = vec.drain(3..6).collect();
+        assert_eq!(drained, ["3", "4", "5"]);
+        assert_values(&vec, &[0, 1, 2, 6, 7, 8, 9]);
+        assert_eq!(vec.capacity(), 12);
+        assert_pinned_prefix(&vec, &addr, 3);
+
+        // shifted elements are at new addresses, the old address of `6` is reused by `9`
+        assert_eq!(vec.index_of_ptr(addr[6]), Some(6));
+        assert_eq!(&vec[6], "9");
+
+        let drained: Vec<_> = vec.drain(..).collect();
+        assert_eq!(drained.len(), 7);
+        assert!(vec.is_empty());
+        assert_eq!(vec.capacity(), 12);
+    }
+
+    #[test]
+    fn drain_partially_consumed() {
+        let mut vec = strings(10, 10);
+        let addr = addresses(&vec);
+
+        let mut drain = vec.drain(5..);
+        assert_eq!(drain.next().as_deref(), Some("5"));
+        drop(drain);
+
+        assert_values(&vec, &[0, 1, 2, 3, 4]);
+        assert_pinned_prefix(&vec, &addr, 5);
+    }
+
+    #[test]
+    fn retain() {
+        let mut vec = strings(10, 10);
+        let addr = addresses(&vec);
+
+        vec.retain(|x| x != "4" && x != "7");
+        assert_values(&vec, &[0, 1, 2, 3, 5, 6, 8, 9]);
+        assert_eq!(vec.capacity(), 10);
+        assert_pinned_prefix(&vec, &addr, 4);
+
+        let mut vec = strings(10, 10);
+        let addr = addresses(&vec);
+        vec.retain(|_| true);
+        assert_pinned_prefix(&vec, &addr, 10);
+    }
+
+    #[test]
+    fn retain_mut() {
+        let mut vec = strings(10, 10);
+        let addr = addresses(&vec);
+
+        vec.retain_mut(|x| {
+            x.push('!');
+            x != "6!"
+        });
+        assert_eq!(vec.len(), 9);
+        assert!(vec.iter().all(|x| x.ends_with('!')));
+        assert_pinned_prefix(&vec, &addr, 6);
+        assert_eq!(&vec[6], "7!");
+    }
+
+    #[test]
+    fn dedup_by_key() {
+        let mut vec = FixedVec::new(10);
+        for x in [0, 1, 11, 12, 2, 22, 3, 4] {
+            vec.push(x.to_string());
+        }
+        let addr = addresses(&vec);
+
+        vec.dedup_by_key(|x| x.len());
+        assert_eq!(vec, ["0", "11", "2", "22", "3"].map(String::from));
+        assert_pinned_prefix(&vec, &addr, 1);
+    }
+
+    #[test]
+    fn split_off() {
+        let mut vec = strings(12, 10);
+        let addr = addresses(&vec);
+
+        let other = vec.split_off(6);
+        assert_values(&vec, &[0, 1, 2, 3, 4, 5]);
+        assert_values(&other, &[6, 7, 8, 9]);
+        assert_eq!(vec.capacity(), 12);
+        assert_eq!(other.capacity(), 4);
+        assert_pinned_prefix(&vec, &addr, 6);
+
+        for (i, x) in other.iter().enumerate() {
+            assert!(!vec.contains_reference(x));
+            assert_eq!(other.index_of(x), Some(i));
+            assert!(!other.contains_ptr(addr[6 + i]));
+        }
+
+        let other = vec.split_off(6);
+        assert!(other.is_empty());
+        assert_eq!(other.capacity(), 0);
+        assert_pinned_prefix(&vec, &addr, 6);
+    }
+
+    #[test]
+    #[should_panic]
+    fn split_off_out_of_bounds() {
+        let mut vec = strings(12, 10);
+        let _ = vec.split_off(11);
+    }
+
+    #[test]
+    fn append() {
+        let mut vec = strings(10, 4);
+        let addr = addresses(&vec);
+
+        let mut other = strings(6, 6);
+        let other_addr = addresses(&other);
+
+        vec.append(&mut other);
+        assert_values(&vec, &[0, 1, 2, 3, 0, 1, 2, 3, 4, 5]);
+        assert!(vec.is_full());
+        assert_pinned_prefix(&vec, &addr, 4);
+
+        assert!(other.is_empty());
+        assert_eq!(other.capacity(), 6);
+        for (i, ptr) in other_addr.iter().enumerate() {
+            assert!(!vec.contains_ptr(*ptr));
+            assert_eq!(vec.index_of(&vec[4 + i]), Some(4 + i));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn append_exceeding_room() {
+        let mut vec = strings(10, 6);
+        let mut other = strings(5, 5);
+        vec.append(&mut other);
+    }
+
+    #[test]
+    fn swap_remove() {
+        let mut vec = strings(10, 6);
+        let addr = addresses(&vec);
+
+        let (removed, relocation) = vec.swap_remove(1);
+        assert_eq!(removed, "1");
+        assert_eq!(relocation, Some((5, 1)));
+        assert_values(&vec, &[0, 5, 2, 3, 4]);
+        assert_eq!(vec.index_of_ptr(addr[1]), Some(1));
+        for i in [0, 2, 3, 4] {
+            assert_eq!(vec.index_of(unsafe { &*addr[i] }), Some(i));
+        }
+
+        let (removed, relocation) = vec.swap_remove(4);
+        assert_eq!(removed, "4");
+        assert_eq!(relocation, None);
+        assert_values(&vec, &[0, 5, 2, 3]);
+        assert_pinned_prefix(&vec, &addr, 1);
+
+        while !vec.is_empty() {
+            let _ = vec.swap_remove(0);
+        }
+        assert_eq!(vec.capacity(), 10);
+    }
+
+    #[test]
+    #[should_panic]
+    fn swap_remove_out_of_bounds() {
+        let mut vec = strings(10, 6);
+        let _ = vec.swap_remove(6);
+    }
+
+    #[test]
+    fn swap_remove_many() {
+        let len = 7;
+        for subset in 0..(1usize << len) {
+            let indices: Vec<_> = (0..len).rev().filter(|i| subset & (1 << i) != 0).collect();
+            let mut vec = strings(10, len);
+            let addr = addresses(&vec);
+
+            let (removed, relocations) = vec.swap_remove_many(&indices);
+
+            let mut expected_removed = indices.clone();
+            expected_removed.sort();
+            assert_eq!(
+                removed,
+                expected_removed
+                    .iter()
+                    .map(|i| i.to_string())
+                    .collect::<Vec<_>>()
+            );
+            assert_eq!(vec.len(), len - indices.len());
+            assert!(relocations.windows(2).all(|w| w[0].1 < w[1].1));
+
+            for (i, x) in vec.iter().enumerate() {
+                let original: usize = x.parse().expect("is-number");
+                assert!(!indices.contains(&original));
+                match relocations.iter().find(|(_, to)| *to == i) {
+                    Some((from, _)) => assert_eq!(*from, original),
+                    // not relocated, hence, pinned
+                    None => assert_eq!(x as *const String, addr[original]),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn swap_remove_many_empty() {
+        let mut vec = strings(10, 4);
+        let addr = addresses(&vec);
+
+        let (removed, relocations) = vec.swap_remove_many(&[]);
+        assert!(removed.is_empty());
+        assert!(relocations.is_empty());
+        assert_pinned_prefix(&vec, &addr, 4);
+
+        let (removed, relocations) = vec.swap_remove_many(&[3, 0, 2, 1]);
+        assert_eq!(removed, ["0", "1", "2", "3"].map(String::from));
+        assert!(relocations.is_empty());
+        assert!(vec.is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn swap_remove_many_duplicates() {
+        let mut vec = strings(10, 6);
+        let _ = vec.swap_remove_many(&[1, 3, 1]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn swap_remove_many_out_of_bounds() {
+        let mut vec = strings(10, 6);
+        let _ = vec.swap_remove_many(&[1, 6]);
+    }
 }
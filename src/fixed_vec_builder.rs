@@ -0,0 +1,202 @@
+use crate::FixedVec;
+use allocator_api2::alloc::{Allocator, Global};
+use core::marker::PhantomData;
+
+/// Builder of a [`FixedVec`] which configures the fixed capacity and the allocator of the vector
+/// before creating it, either empty or filled with initial elements; created by [`FixedVec::builder`].
+///
+/// The fixed capacity defaults to zero and the allocator defaults to [`Global`].
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_fixed_vec::prelude::*;
+///
+/// // capacity from a length hint, such as the size hint of an iterator
+/// let words = ["a", "b", "c"];
+/// let mut vec = FixedVec::builder()
+///     .capacity_from_hint(words.iter().size_hint())
+///     .build();
+/// vec.extend(words.iter().map(|x| x.to_uppercase()));
+/// assert_eq!(vec, ["A", "B", "C"].map(String::from));
+/// assert!(vec.is_full());
+///
+/// // full capacity filled with values created by a closure
+/// let vec = FixedVec::builder().capacity(3).fill_with(|| 7);
+/// assert_eq!(vec, &[7, 7, 7]);
+///
+/// // full capacity filled with values computed from positions
+/// let vec = FixedVec::builder().capacity(4).from_fn(|i| i * i);
+/// assert_eq!(vec, &[0, 1, 4, 9]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct FixedVecBuilder<T, A: Allocator = Global> {
+    capacity: usize,
+    alloc: A,
+    phantom: PhantomData<fn() -> T>,
+}
+
+impl<T> FixedVecBuilder<T> {
+    /// Creates a builder with zero fixed capacity using the [`Global`] allocator.
+    pub fn new() -> Self {
+        Self::new_in(Global)
+    }
+}
+
+impl<T> Default for FixedVecBuilder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, A: Allocator> FixedVecBuilder<T, A> {
+    /// Creates a builder with zero fixed capacity using the given allocator `alloc`.
+    pub fn new_in(alloc: A) -> Self {
+        Self {
+            capacity: 0,
+            alloc,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Sets the fixed capacity of the vector to be built.
+    pub fn capacity(mut self, fixed_capacity: usize) -> Self {
+        self.capacity = fixed_capacity;
+        self
+    }
+
+    /// Sets the fixed capacity of the vector to be built from the given length hint in the form of
+    /// `Iterator::size_hint`: the upper bound if it is known, and the lower bound otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_fixed_vec::prelude::*;
+    ///
+    /// let vec = FixedVec::<char>::builder().capacity_from_hint((2, Some(5))).build();
+    /// assert_eq!(vec.capacity(), 5);
+    ///
+    /// let vec = FixedVec::<char>::builder().capacity_from_hint((2, None)).build();
+    /// assert_eq!(vec.capacity(), 2);
+    /// ```
+    pub fn capacity_from_hint(self, (lower, upper): (usize, Option<usize>)) -> Self {
+        self.capacity(upper.unwrap_or(lower))
+    }
+
+    /// Replaces the allocator of the vector to be built with `alloc`.
+    pub fn allocator<B: Allocator>(self, alloc: B) -> FixedVecBuilder<T, B> {
+        FixedVecBuilder {
+            capacity: self.capacity,
+            alloc,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Creates an empty vector with the configured fixed capacity and allocator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the required memory exceeds `isize::MAX` bytes.
+    pub fn build(self) -> FixedVec<T, A> {
+        FixedVec::new_in(self.capacity, self.alloc)
+    }
+
+    /// Creates a vector with the configured fixed capacity and allocator, which is filled up to its capacity
+    /// with elements created by repeatedly calling `f`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the required memory exceeds `isize::MAX` bytes.
+    pub fn fill_with<F>(self, mut f: F) -> FixedVec<T, A>
+    where
+        F: FnMut() -> T,
+    {
+        self.from_fn(|_| f())
+    }
+
+    /// Creates a vector with the configured fixed capacity and allocator, which is filled up to its capacity
+    /// such that the element at position `i` is `f(i)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the required memory exceeds `isize::MAX` bytes.
+    pub fn from_fn<F>(self, f: F) -> FixedVec<T, A>
+    where
+        F: FnMut(usize) -> T,
+    {
+        let mut vec = self.build();
+        vec.fill_room_with(f);
+        vec
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Global;
+    use crate::prelude::*;
+    use alloc::string::{String, ToString};
+
+    #[test]
+    fn build_empty() {
+        let vec = FixedVecBuilder::<String>::new().build();
+        assert!(vec.is_empty());
+        assert_eq!(vec.capacity(), 0);
+
+        let vec = FixedVec::<String>::builder().capacity(7).build();
+        assert!(vec.is_empty());
+        assert_eq!(vec.capacity(), 7);
+
+        let vec = FixedVecBuilder::<u8>::new_in(Global)
+            .capacity(3)
+            .allocator(Global)
+            .build();
+        assert_eq!(vec.capacity(), 3);
+    }
+
+    #[test]
+    fn capacity_from_hint() {
+        let iter = (0..10).filter(|x| x % 2 == 0);
+        let vec = FixedVec::<i32>::builder()
+            .capacity_from_hint(iter.size_hint())
+            .build();
+        assert_eq!(vec.capacity(), 10);
+
+        let iter = core::iter::repeat(1).take_while(|x| *x < 5);
+        let vec = FixedVec::<i32>::builder()
+            .capacity(42)
+            .capacity_from_hint(iter.size_hint())
+            .build();
+        assert_eq!(vec.capacity(), 0);
+
+        let vec = FixedVec::<i32>::builder()
+            .capacity_from_hint((3, None))
+            .build();
+        assert_eq!(vec.capacity(), 3);
+    }
+
+    #[test]
+    fn fill_with() {
+        let mut count = 0;
+        let vec = FixedVec::builder().capacity(4).fill_with(|| {
+            count += 1;
+            count.to_string()
+        });
+        assert_eq!(vec, ["1", "2", "3", "4"].map(String::from));
+        assert!(vec.is_full());
+
+        let vec = FixedVec::builder().fill_with(|| 'x');
+        assert!(vec.is_empty());
+        assert!(vec.is_full());
+    }
+
+    #[test]
+    fn from_fn() {
+        let vec = FixedVec::builder().capacity(5).from_fn(|i| i * 2);
+        assert_eq!(vec, &[0, 2, 4, 6, 8]);
+        assert!(vec.is_full());
+
+        let vec = FixedVec::builder().capacity(3).from_fn(|_| ());
+        assert_eq!(vec.len(), 3);
+        assert_eq!(vec.capacity(), 3);
+    }
+}
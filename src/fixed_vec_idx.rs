@@ -0,0 +1,431 @@
+use crate::FixedVec;
+use allocator_api2::alloc::Allocator;
+use core::fmt::Debug;
+use core::hash::Hash;
+use core::marker::PhantomData;
+use core::ops::{Index, IndexMut};
+
+/// A typed handle to an element of a [`FixedVec`].
+///
+/// Since the elements of a fixed vector are pinned, the position of an element is a stable key as long as
+/// the element is not removed or moved by a reordering operation such as `insert`, `remove` or `sort`.
+/// In addition to the position, the handle keeps the address of the buffer and the generation of the vector
+/// it is created from. The generation of a vector is bumped whenever an element at an existing position is
+/// removed or moved, such as by `clear`, `truncate`, `insert`, `remove`, `swap_remove`, `retain`, `drain`,
+/// `split_off` or `sort`; pushing elements or removing the last element by `pop` keeps the generation.
+///
+/// Therefore, methods taking a handle such as [`FixedVec::get_by_idx`] reject handles created from a
+/// different vector as well as the handles created before elements of the vector are removed or moved.
+/// A handle to a popped element is rejected as out of bounds until an element is pushed to the same position.
+///
+/// Note that the identity of vectors which do not allocate, such as vectors with zero capacity or vectors
+/// of zero-sized types, cannot be distinguished by the buffer address. Similarly, a handle of a dropped vector
+/// might be accepted by a new vector which happens to reuse the same buffer. Further, elements swapped by
+/// `swap` or reordered through mutable slices, such as by `as_mut().reverse()`, are not tracked.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_fixed_vec::prelude::*;
+///
+/// let mut a = FixedVec::new(4);
+/// let mut b = FixedVec::new(4);
+///
+/// let idx_a = a.push_get_idx('a');
+/// let idx_b = b.push_get_idx('b');
+///
+/// assert_eq!(a.get_by_idx(idx_a), Some(&'a'));
+/// assert_eq!(b[idx_b], 'b');
+///
+/// // handle of another vector is rejected
+/// assert_eq!(a.get_by_idx(idx_b), None);
+///
+/// // handles created before elements are removed are rejected
+/// a.clear();
+/// a.push('x');
+/// assert_eq!(a.get_by_idx(idx_a), None);
+/// ```
+pub struct FixedVecIdx<T> {
+    addr: usize,
+    generation: usize,
+    index: usize,
+    phantom: PhantomData<fn() -> T>,
+}
+
+impl<T> FixedVecIdx<T> {
+    /// Returns the position of the element in the vector that the handle is created from.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+impl<T> Clone for FixedVecIdx<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for FixedVecIdx<T> {}
+
+impl<T> PartialEq for FixedVecIdx<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.addr == other.addr && self.generation == other.generation && self.index == other.index
+    }
+}
+
+impl<T> Eq for FixedVecIdx<T> {}
+
+impl<T> Hash for FixedVecIdx<T> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.addr.hash(state);
+        self.generation.hash(state);
+        self.index.hash(state);
+    }
+}
+
+impl<T> Debug for FixedVecIdx<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FixedVecIdx")
+            .field("generation", &self.generation)
+            .field("index", &self.index)
+            .finish()
+    }
+}
+
+impl<T, A: Allocator> FixedVec<T, A> {
+    /// Pushes the `value` to the vector and returns the handle to the pushed element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no available room in the vector;
+    /// i.e., `self.is_full()` or equivalently `self.len() == self.capacity()`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_fixed_vec::prelude::*;
+    ///
+    /// let mut vec = FixedVec::new(4);
+    /// vec.push(0);
+    ///
+    /// let idx = vec.push_get_idx(1);
+    /// assert_eq!(idx.index(), 1);
+    /// assert_eq!(vec.get_by_idx(idx), Some(&1));
+    /// ```
+    pub fn push_get_idx(&mut self, value: T) -> FixedVecIdx<T> {
+        self.push_or_panic(value);
+        self.create_idx(self.data.len() - 1)
+    }
+
+    /// Returns the handle to the element at the given `index`; None if the `index` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_fixed_vec::prelude::*;
+    ///
+    /// let mut vec: FixedVec<_> = FixedVec::new(4);
+    /// vec.extend_from_slice(&['a', 'b']);
+    ///
+    /// let idx = vec.idx_at(1).unwrap();
+    /// assert_eq!(vec[idx], 'b');
+    /// assert!(vec.idx_at(2).is_none());
+    /// ```
+    pub fn idx_at(&self, index: usize) -> Option<FixedVecIdx<T>> {
+        (index < self.data.len()).then(|| self.create_idx(index))
+    }
+
+    /// Returns the handle to the given `element`; None if the element does not belong to this vector.
+    ///
+    /// Similar to `index_of`, reference equality is used, and hence, `T: Eq` is not required.
+    /// This method has *O(1)* time complexity.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_fixed_vec::prelude::*;
+    ///
+    /// let mut vec: FixedVec<_> = FixedVec::new(4);
+    /// vec.extend_from_slice(&['a', 'b']);
+    ///
+    /// let idx = vec.idx_of(&vec[1]).unwrap();
+    /// assert_eq!(idx.index(), 1);
+    /// assert!(vec.idx_of(&'b').is_none());
+    /// ```
+    pub fn idx_of(&self, element: &T) -> Option<FixedVecIdx<T>> {
+        orx_pinned_vec::utils::slice::index_of(&self.data, element).map(|i| self.create_idx(i))
+    }
+
+    /// Returns true if the handle is created from this vector, after the last time its elements are
+    /// removed or moved, and it points to a position within the bounds of the vector.
+    pub fn contains_idx(&self, idx: FixedVecIdx<T>) -> bool {
+        idx.addr == self.addr() && idx.generation == self.generation && idx.index < self.data.len()
+    }
+
+    /// Returns a reference to the element of the handle `idx`;
+    /// None if [`contains_idx`](Self::contains_idx) is false.
+    pub fn get_by_idx(&self, idx: FixedVecIdx<T>) -> Option<&T> {
+        match self.contains_idx(idx) {
+            true => Some(&self.data[idx.index]),
+            false => None,
+        }
+    }
+
+    /// Returns a mutable reference to the element of the handle `idx`;
+    /// None if [`contains_idx`](Self::contains_idx) is false.
+    pub fn get_mut_by_idx(&mut self, idx: FixedVecIdx<T>) -> Option<&mut T> {
+        match self.contains_idx(idx) {
+            true => Some(&mut self.data[idx.index]),
+            false => None,
+        }
+    }
+
+    // helpers
+
+    /// Bumps the generation of the vector so that all handles created so far are rejected.
+    #[inline(always)]
+    pub(crate) fn invalidate_indices(&mut self) {
+        self.generation = self.generation.wrapping_add(1);
+    }
+
+    /// Bumps the generation of the vector if its length is decreased below the given `len`.
+    #[inline(always)]
+    pub(crate) fn invalidate_indices_if_shorter_than(&mut self, len: usize) {
+        if self.data.len() < len {
+            self.invalidate_indices();
+        }
+    }
+
+    #[inline(always)]
+    fn addr(&self) -> usize {
+        self.data.as_ptr().addr()
+    }
+
+    #[inline(always)]
+    fn create_idx(&self, index: usize) -> FixedVecIdx<T> {
+        FixedVecIdx {
+            addr: self.addr(),
+            generation: self.generation,
+            index,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, A: Allocator> Index<FixedVecIdx<T>> for FixedVec<T, A> {
+    type Output = T;
+
+    /// Returns a reference to the element of the handle `idx`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the handle is not valid for this vector; see [`FixedVec::contains_idx`].
+    fn index(&self, idx: FixedVecIdx<T>) -> &Self::Output {
+        self.get_by_idx(idx).expect(ERR_MSG_INVALID_IDX)
+    }
+}
+
+impl<T, A: Allocator> IndexMut<FixedVecIdx<T>> for FixedVec<T, A> {
+    /// Returns a mutable reference to the element of the handle `idx`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the handle is not valid for this vector; see [`FixedVec::contains_idx`].
+    fn index_mut(&mut self, idx: FixedVecIdx<T>) -> &mut Self::Output {
+        self.get_mut_by_idx(idx).expect(ERR_MSG_INVALID_IDX)
+    }
+}
+
+const ERR_MSG_INVALID_IDX: &str = "FixedVecIdx does not belong to this FixedVec or it is invalidated by removing or moving elements.";
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use alloc::string::{String, ToString};
+    use alloc::vec::Vec;
+
+    #[test]
+    fn push_get_idx() {
+        let mut vec = FixedVec::new(10);
+        let indices: Vec<_> = (0..10).map(|i| vec.push_get_idx(i.to_string())).collect();
+
+        for (i, idx) in indices.iter().enumerate() {
+            assert_eq!(idx.index(), i);
+            assert!(vec.contains_idx(*idx));
+            assert_eq!(vec.get_by_idx(*idx), Some(&i.to_string()));
+            assert_eq!(&vec[*idx], &i.to_string());
+        }
+
+        for idx in &indices {
+            vec[*idx].push('!');
+        }
+        assert!(vec.iter().all(|x| x.ends_with('!')));
+    }
+
+    #[test]
+    #[should_panic]
+    fn push_get_idx_when_full() {
+        let mut vec = FixedVec::new(1);
+        vec.push_get_idx(0);
+        vec.push_get_idx(1);
+    }
+
+    #[test]
+    fn idx_at_and_idx_of() {
+        let vec: FixedVec<_> = (0..5).collect();
+
+        for i in 0..5 {
+            let idx = vec.idx_at(i).expect("in-bounds");
+            assert_eq!(Some(idx), vec.idx_of(&vec[i]));
+            assert_eq!(vec[idx], i);
+        }
+
+        assert!(vec.idx_at(5).is_none());
+        assert!(vec.idx_of(&3).is_none());
+    }
+
+    #[test]
+    fn rejects_idx_of_other_vec() {
+        let mut a = FixedVec::new(4);
+        let mut b = FixedVec::new(4);
+        let idx_a = a.push_get_idx('a');
+        let idx_b = b.push_get_idx('b');
+
+        assert_ne!(idx_a, idx_b);
+        assert!(!a.contains_idx(idx_b));
+        assert!(!b.contains_idx(idx_a));
+        assert_eq!(a.get_by_idx(idx_b), None);
+        assert_eq!(b.get_mut_by_idx(idx_a), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_with_idx_of_other_vec() {
+        let mut a = FixedVec::new(4);
+        let mut b = FixedVec::new(4);
+        a.push_get_idx('a');
+        let idx_b = b.push_get_idx('b');
+        let _ = a[idx_b];
+    }
+
+    #[test]
+    fn rejects_idx_after_clear() {
+        let mut vec: FixedVec<String> = FixedVec::new(4);
+        let idx = vec.push_get_idx("a".to_string());
+
+        vec.clear();
+        assert!(!vec.contains_idx(idx));
+
+        let new_idx = vec.push_get_idx("b".to_string());
+        assert_eq!(new_idx.index(), idx.index());
+        assert_ne!(new_idx, idx);
+        assert_eq!(vec.get_by_idx(idx), None);
+        assert_eq!(vec.get_by_idx(new_idx).map(|x| x.as_str()), Some("b"));
+    }
+
+    #[test]
+    fn rejects_idx_after_removing_or_moving_elements() {
+        type Op = fn(&mut FixedVec<String>);
+        let ops: [Op; 11] = [
+            |v| v.truncate(0),
+            |v| v.truncate(2),
+            |v| _ = v.drain(..),
+            |v| v.retain(|x| x != "b"),
+            |v| _ = v.split_off(0),
+            |v| _ = v.swap_remove(0),
+            |v| _ = v.swap_remove_many(&[1]),
+            |v| v.insert(0, "x".to_string()),
+            |v| _ = v.remove(1),
+            |v| v.sort_by(|a, b| b.cmp(a)),
+            |v| v.dedup_by_key(|x| x.len()),
+        ];
+
+        for op in ops {
+            let mut vec = FixedVec::new(4);
+            let indices: Vec<_> = ["a", "b", "c"]
+                .map(|x| vec.push_get_idx(x.to_string()))
+                .to_vec();
+
+            op(&mut vec);
+            for idx in indices {
+                assert!(!vec.contains_idx(idx));
+                assert_eq!(vec.get_by_idx(idx), None);
+            }
+        }
+    }
+
+    #[test]
+    fn idx_survives_push_and_mutation_in_place() {
+        let mut vec = FixedVec::new(4);
+        let idx = vec.push_get_idx(1);
+
+        vec.push(2);
+        vec.extend_from_slice(&[3]);
+        vec[idx] += 10;
+        assert_eq!(vec.get_by_idx(idx), Some(&11));
+    }
+
+    #[test]
+    fn idx_survives_operations_keeping_its_position() {
+        type Op = fn(&mut FixedVec<String>);
+        let ops: [Op; 9] = [
+            |v| v.truncate(3),
+            |v| _ = v.drain(1..1),
+            |v| v.retain(|_| true),
+            |v| _ = v.split_off(3),
+            |v| _ = v.swap_remove(2),
+            |v| v.insert(3, "x".to_string()),
+            |v| _ = v.remove(2),
+            |v| _ = v.pop(),
+            |v| {
+                v.pop();
+                v.push("z".to_string());
+            },
+        ];
+
+        for op in ops {
+            let mut vec = FixedVec::new(4);
+            let idx = vec.push_get_idx("a".to_string());
+            vec.extend_from_slice(&["b".to_string(), "c".to_string()]);
+
+            op(&mut vec);
+            assert_eq!(vec.get_by_idx(idx).map(|x| x.as_str()), Some("a"));
+        }
+    }
+
+    #[test]
+    fn rejects_idx_of_cloned_vec() {
+        let mut vec = FixedVec::new(4);
+        let idx = vec.push_get_idx('a');
+
+        let clone = vec.clone();
+        assert_eq!(clone[0], 'a');
+        assert!(!clone.contains_idx(idx));
+    }
+
+    #[test]
+    fn rejects_idx_out_of_bounds() {
+        let mut vec = FixedVec::new(4);
+        vec.push(0);
+        let idx = vec.push_get_idx(1);
+
+        assert_eq!(vec.pop(), Some(1));
+        assert!(!vec.contains_idx(idx));
+        assert_eq!(vec.get_by_idx(idx), None);
+    }
+
+    #[test]
+    fn idx_survives_concurrent_round_trip() {
+        let mut vec = FixedVec::new(4);
+        let idx = vec.push_get_idx(42);
+
+        let con_vec = vec.into_concurrent();
+        let vec = unsafe { con_vec.into_inner(1) };
+        assert_eq!(vec.get_by_idx(idx), Some(&42));
+
+        let mut con_vec = vec.into_concurrent();
+        unsafe { con_vec.clear(1) };
+        let vec = unsafe { con_vec.into_inner(0) };
+        assert!(!vec.contains_idx(idx));
+    }
+}
@@ -0,0 +1,53 @@
+//! Elements counting their creations and drops, shared by the tests to detect leaks and double drops.
+
+use alloc::sync::Arc;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+#[derive(Default)]
+struct Counts {
+    created: AtomicUsize,
+    dropped: AtomicUsize,
+}
+
+/// Counts the created and dropped [`Tracked`] elements.
+///
+/// Clones of the counter share the counts; hence, elements might be created and dropped by different threads.
+#[derive(Clone, Default)]
+pub struct DropCounter(Arc<Counts>);
+
+impl DropCounter {
+    /// Creates a counter with zero created and dropped elements.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new element tracked by this counter.
+    pub fn track(&self) -> Tracked {
+        self.0.created.fetch_add(1, Ordering::SeqCst);
+        Tracked(self.0.clone())
+    }
+
+    /// Number of elements created so far.
+    pub fn created(&self) -> usize {
+        self.0.created.load(Ordering::SeqCst)
+    }
+
+    /// Number of elements dropped so far.
+    pub fn dropped(&self) -> usize {
+        self.0.dropped.load(Ordering::SeqCst)
+    }
+
+    /// Asserts that every created element is dropped exactly once.
+    pub fn assert_no_leak(&self) {
+        assert_eq!(self.created(), self.dropped());
+    }
+}
+
+/// Element incrementing the drop count of the counter it is created by when dropped.
+pub struct Tracked(Arc<Counts>);
+
+impl Drop for Tracked {
+    fn drop(&mut self) {
+        self.0.dropped.fetch_add(1, Ordering::SeqCst);
+    }
+}
@@ -0,0 +1,102 @@
+use core::ops::Range;
+
+/// Writes `fill_with()` into every position within `range` of the buffer starting at `ptr`
+/// with `capacity` positions.
+///
+/// If `fill_with` panics, the elements written so far by this call are dropped before the panic
+/// propagates; hence, positions within `range` are either all initialized or all uninitialized.
+///
+/// # Safety
+///
+/// `ptr` must be valid for writes of `capacity` elements, and positions within `range` must be
+/// uninitialized and must not be accessed by any other thread during the call.
+///
+/// # Panics
+///
+/// Panics if `range` is not empty and it exceeds the `capacity`.
+pub(crate) unsafe fn fill_range_with<T, F>(
+    ptr: *mut T,
+    capacity: usize,
+    range: Range<usize>,
+    fill_with: F,
+) where
+    F: Fn() -> T,
+{
+    assert!(range.is_empty() || range.end <= capacity);
+
+    let mut guard = DropWrittenOnUnwind {
+        ptr,
+        written: range.start..range.start,
+    };
+    for i in range {
+        // SAFETY: i < capacity and the position is uninitialized
+        unsafe { ptr.add(i).write(fill_with()) };
+        guard.written.end += 1;
+    }
+    core::mem::forget(guard);
+}
+
+/// Drops the elements within `written` positions of the buffer starting at `ptr` unless forgotten.
+struct DropWrittenOnUnwind<T> {
+    ptr: *mut T,
+    written: Range<usize>,
+}
+
+impl<T> Drop for DropWrittenOnUnwind<T> {
+    fn drop(&mut self) {
+        // SAFETY: positions within `written` are initialized by `fill_range_with`
+        unsafe {
+            let first = self.ptr.add(self.written.start);
+            let slice = core::ptr::slice_from_raw_parts_mut(first, self.written.len());
+            core::ptr::drop_in_place(slice);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::drop_counter::{DropCounter, Tracked};
+    use alloc::vec::Vec;
+    use core::cell::Cell;
+
+    #[test]
+    fn fill_range() {
+        let mut vec = Vec::<usize>::with_capacity(6);
+        let ptr = vec.as_mut_ptr();
+        let next = Cell::new(0);
+
+        unsafe { fill_range_with(ptr, 6, 2..5, || next.replace(next.get() + 1)) };
+        unsafe { fill_range_with(ptr, 6, 0..2, || 42) };
+        unsafe { fill_range_with(ptr, 6, 6..6, || 7) };
+        unsafe { vec.set_len(5) };
+
+        assert_eq!(vec, [42, 42, 0, 1, 2]);
+    }
+
+    #[test]
+    fn fill_range_drops_written_on_panic() {
+        let drops = DropCounter::new();
+        let mut vec = Vec::<Tracked>::with_capacity(8);
+        let ptr = vec.as_mut_ptr();
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| unsafe {
+            fill_range_with(ptr, 8, 1..7, || {
+                assert!(drops.created() < 4);
+                drops.track()
+            })
+        }));
+
+        assert!(result.is_err());
+        assert_eq!(drops.created(), 4);
+        drops.assert_no_leak();
+        assert!(vec.is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn fill_range_out_of_capacity() {
+        let mut vec = Vec::<usize>::with_capacity(4);
+        unsafe { fill_range_with(vec.as_mut_ptr(), 4, 2..5, || 0) };
+    }
+}
@@ -1 +1,5 @@
+#[cfg(test)]
+pub mod drop_counter;
+pub mod fill;
 pub mod range;
+pub mod vec;
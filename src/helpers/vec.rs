@@ -0,0 +1,58 @@
+use alloc::vec::Vec;
+use allocator_api2::{alloc::Global, vec::Vec as AllocVec};
+use core::mem::ManuallyDrop;
+
+/// Returns true if `T` is a zero-sized type, for which vectors report a capacity of `usize::MAX`
+/// without allocating; hence, the capacity of the inner vector cannot be used as the fixed capacity.
+#[inline(always)]
+pub(crate) const fn is_zst<T>() -> bool {
+    core::mem::size_of::<T>() == 0
+}
+
+/// Converts the standard vector into an allocator-aware vector without any allocation.
+#[inline]
+pub(crate) fn from_std_vec<T>(vec: Vec<T>) -> AllocVec<T, Global> {
+    let mut vec = ManuallyDrop::new(vec);
+    let (ptr, len, capacity) = (vec.as_mut_ptr(), vec.len(), vec.capacity());
+    // SAFETY: `Global` delegates to the global allocator which allocated the buffer of `vec`
+    unsafe { AllocVec::from_raw_parts(ptr, len, capacity) }
+}
+
+/// Converts the allocator-aware vector using the global allocator into a standard vector without any allocation.
+#[inline]
+pub(crate) fn into_std_vec<T>(vec: AllocVec<T, Global>) -> Vec<T> {
+    let mut vec = ManuallyDrop::new(vec);
+    let (ptr, len, capacity) = (vec.as_mut_ptr(), vec.len(), vec.capacity());
+    // SAFETY: `Global` delegates to the global allocator which will deallocate the buffer of the returned vector
+    unsafe { Vec::from_raw_parts(ptr, len, capacity) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::{String, ToString};
+
+    #[test]
+    fn round_trip() {
+        let mut vec: Vec<String> = Vec::with_capacity(7);
+        vec.extend((0..4).map(|x| x.to_string()));
+        let ptr = vec.as_ptr();
+
+        let alloc_vec = from_std_vec(vec);
+        assert_eq!(alloc_vec.as_ptr(), ptr);
+        assert_eq!(alloc_vec.len(), 4);
+        assert_eq!(alloc_vec.capacity(), 7);
+
+        let vec = into_std_vec(alloc_vec);
+        assert_eq!(vec.as_ptr(), ptr);
+        assert_eq!(vec.capacity(), 7);
+        assert_eq!(vec, ["0", "1", "2", "3"]);
+    }
+
+    #[test]
+    fn round_trip_empty() {
+        let vec: Vec<char> = Vec::new();
+        let vec = into_std_vec(from_std_vec(vec));
+        assert!(vec.is_empty());
+    }
+}
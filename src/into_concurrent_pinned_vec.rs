@@ -1,8 +1,9 @@
 use crate::{ConcurrentFixedVec, FixedVec};
+use allocator_api2::alloc::Allocator;
 use orx_pinned_vec::IntoConcurrentPinnedVec;
 
-impl<T> IntoConcurrentPinnedVec<T> for FixedVec<T> {
-    type ConPinnedVec = ConcurrentFixedVec<T>;
+impl<T, A: Allocator + Clone + Default> IntoConcurrentPinnedVec<T> for FixedVec<T, A> {
+    type ConPinnedVec = ConcurrentFixedVec<T, A>;
 
     fn into_concurrent(self) -> Self::ConPinnedVec {
         self.into()
@@ -12,7 +13,7 @@ impl<T> IntoConcurrentPinnedVec<T> for FixedVec<T> {
     where
         F: Fn() -> T,
     {
-        let (len, capacity) = (self.data.len(), self.data.capacity());
+        let (len, capacity) = (self.data.len(), self.capacity);
         for _ in len..capacity {
             self.data.push(fill_with());
         }
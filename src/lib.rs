@@ -10,24 +10,49 @@
     clippy::missing_panics_doc,
     clippy::todo
 )]
-#![cfg_attr(not(test), no_std)]
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
+#![cfg_attr(feature = "nightly", feature(allocator_api))]
 
 extern crate alloc;
 
 mod common_traits;
 mod con_pinned_vec;
 mod concurrent_iter;
+mod errors;
+mod fixed_array_vec;
+mod fixed_ring;
+mod fixed_slab;
 mod fixed_vec;
+mod fixed_vec_builder;
+mod fixed_vec_idx;
 mod helpers;
 mod into_concurrent_pinned_vec;
+#[cfg(feature = "mmap")]
+mod mmap_fixed_vec;
+#[cfg(feature = "std")]
+mod par_from_fn;
 mod pinned_vec;
+mod spare_capacity;
 
 /// Common relevant traits, structs, enums.
 pub mod prelude;
 
-pub use con_pinned_vec::ConcurrentFixedVec;
+pub use allocator_api2::alloc::{Allocator, Global};
+#[cfg(feature = "mmap")]
+pub use bytemuck::Pod;
+pub use common_traits::FixedVecIntoIter;
+pub use con_pinned_vec::{ConcurrentFixedBag, ConcurrentFixedVec};
+pub use errors::FixedVecFullError;
+pub use fixed_array_vec::{ConcurrentFixedArrayVec, FixedArrayVec, FixedArrayVecIntoIter};
+pub use fixed_ring::FixedRing;
+pub use fixed_slab::FixedSlab;
 pub use fixed_vec::FixedVec;
+pub use fixed_vec_builder::FixedVecBuilder;
+pub use fixed_vec_idx::FixedVecIdx;
+#[cfg(feature = "mmap")]
+pub use mmap_fixed_vec::{ConcurrentMmapFixedVec, MmapFixedVec, MmapFixedVecIntoIter};
 pub use orx_iterable::{Collection, CollectionMut, Iterable};
 pub use orx_pinned_vec::{
     ConcurrentPinnedVec, IntoConcurrentPinnedVec, PinnedVec, PinnedVecGrowthError,
 };
+pub use spare_capacity::SpareCapacityWriter;
@@ -0,0 +1,76 @@
+use super::MmapFixedVec;
+use bytemuck::Pod;
+use core::fmt::Debug;
+use core::ops::{Deref, Index, IndexMut};
+use core::slice::SliceIndex;
+
+// as-ref
+
+impl<T: Pod> AsRef<[T]> for MmapFixedVec<T> {
+    fn as_ref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T: Pod> AsMut<[T]> for MmapFixedVec<T> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T: Pod> Deref for MmapFixedVec<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+// debug
+
+impl<T> Debug for MmapFixedVec<T>
+where
+    T: Pod + Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("MmapFixedVec")
+            .field("data", &self.as_slice())
+            .finish()
+    }
+}
+
+// eq
+
+impl<T, U> PartialEq<U> for MmapFixedVec<T>
+where
+    U: AsRef<[T]>,
+    T: Pod + PartialEq,
+{
+    fn eq(&self, other: &U) -> bool {
+        self.as_slice() == other.as_ref()
+    }
+}
+
+// index
+
+impl<T, I> Index<I> for MmapFixedVec<T>
+where
+    T: Pod,
+    I: SliceIndex<[T]>,
+{
+    type Output = I::Output;
+
+    fn index(&self, index: I) -> &Self::Output {
+        &self.as_slice()[index]
+    }
+}
+
+impl<T, I> IndexMut<I> for MmapFixedVec<T>
+where
+    T: Pod,
+    I: SliceIndex<[T]>,
+{
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        &mut self.as_mut_slice()[index]
+    }
+}
@@ -0,0 +1,275 @@
+use super::mmap_vec::ERR_MSG_MAP_ANON;
+use super::{MmapFixedVec, MmapFixedVecIntoIter};
+use crate::{
+    common_traits::ptr_iter::FixedVecPtrIter,
+    helpers::range::{range_end, range_start},
+};
+use bytemuck::Pod;
+use core::fmt::Debug;
+use core::ptr::NonNull;
+use core::{cmp::Ordering, ops::Range};
+use orx_pinned_vec::{ConcurrentPinnedVec, IntoConcurrentPinnedVec, PinnedVecGrowthError};
+
+/// Concurrent wrapper ([`orx_pinned_vec::ConcurrentPinnedVec`]) for the [`MmapFixedVec`].
+///
+/// Elements are written concurrently directly into the mapped memory; the length stored in the file is updated
+/// when the vector is converted back by `into_inner`, or set by `set_pinned_vec_len` or `clear`.
+///
+/// # Thread safety
+///
+/// `ConcurrentMmapFixedVec<T>` is `Send` if `T: Send`, and it is `Sync` if `T: Send + Sync`.
+/// It follows the same contract on concurrent method calls as the [`ConcurrentFixedVec`](crate::ConcurrentFixedVec).
+pub struct ConcurrentMmapFixedVec<T: Pod> {
+    vec: MmapFixedVec<T>,
+    // pointer to the elements cached from the vector
+    ptr: NonNull<T>,
+}
+
+// SAFETY: the wrapper owns the mapped vector.
+unsafe impl<T: Pod + Send> Send for ConcurrentMmapFixedVec<T> {}
+
+// SAFETY: through a shared reference, elements might be written (T: Send) and referenced (T: Sync).
+unsafe impl<T: Pod + Send + Sync> Sync for ConcurrentMmapFixedVec<T> {}
+
+impl<T: Pod> Debug for ConcurrentMmapFixedVec<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ConcurrentMmapFixedVec")
+            .field("fixed_capacity", &self.vec.capacity())
+            .finish()
+    }
+}
+
+impl<T: Pod> From<MmapFixedVec<T>> for ConcurrentMmapFixedVec<T> {
+    fn from(vec: MmapFixedVec<T>) -> Self {
+        let ptr = vec.data_ptr();
+        Self { vec, ptr }
+    }
+}
+
+impl<T: Pod> ConcurrentMmapFixedVec<T> {
+    #[inline(always)]
+    fn ptr_at(&self, index: usize) -> *mut T {
+        // SAFETY: callers guarantee that index is within 0..=capacity
+        unsafe { self.ptr.add(index).as_ptr() }
+    }
+}
+
+impl<T: Pod> ConcurrentPinnedVec<T> for ConcurrentMmapFixedVec<T> {
+    type P = MmapFixedVec<T>;
+
+    type SliceIter<'a>
+        = Option<&'a [T]>
+    where
+        T: 'a,
+        Self: 'a;
+
+    type SliceMutIter<'a>
+        = Option<&'a mut [T]>
+    where
+        T: 'a,
+        Self: 'a;
+
+    type PtrIter<'a>
+        = FixedVecPtrIter<T>
+    where
+        Self: 'a;
+
+    type IntoIter = MmapFixedVecIntoIter<T>;
+
+    unsafe fn into_inner(mut self, len: usize) -> Self::P {
+        self.vec.set_len_unchecked(len);
+        self.vec
+    }
+
+    /// Clones the first `len` elements into a new concurrent vector with the same capacity.
+    ///
+    /// Note that the clone is mapped to anonymous memory rather than to a file.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the memory cannot be mapped.
+    unsafe fn clone_with_len(&self, len: usize) -> Self
+    where
+        T: Clone,
+    {
+        assert!(len <= self.capacity());
+        let mut clone = MmapFixedVec::new_anonymous(self.capacity()).expect(ERR_MSG_MAP_ANON);
+        // SAFETY: positions within 0..len are written, and the clone has room for them
+        let slice = unsafe { core::slice::from_raw_parts(self.ptr.as_ptr(), len) };
+        for x in slice {
+            clone.push_or_panic(*x);
+        }
+        clone.into()
+    }
+
+    fn capacity(&self) -> usize {
+        self.vec.capacity()
+    }
+
+    fn max_capacity(&self) -> usize {
+        self.vec.capacity()
+    }
+
+    fn grow_to(&self, new_capacity: usize) -> Result<usize, PinnedVecGrowthError> {
+        match new_capacity <= self.capacity() {
+            true => Ok(self.capacity()),
+            false => Err(PinnedVecGrowthError::FailedToGrowWhileKeepingElementsPinned),
+        }
+    }
+
+    fn grow_to_and_fill_with<F>(
+        &self,
+        new_capacity: usize,
+        _: F,
+    ) -> Result<usize, PinnedVecGrowthError>
+    where
+        F: Fn() -> T,
+    {
+        match new_capacity <= self.capacity() {
+            true => Ok(self.capacity()),
+            false => Err(PinnedVecGrowthError::FailedToGrowWhileKeepingElementsPinned),
+        }
+    }
+
+    fn fill_with<F>(&self, range: Range<usize>, fill_with: F)
+    where
+        F: Fn() -> T,
+    {
+        for i in range {
+            unsafe { self.get_ptr_mut(i).write(fill_with()) };
+        }
+    }
+
+    fn slices<R: core::ops::RangeBounds<usize>>(&self, range: R) -> Option<&[T]> {
+        let a = range_start(&range);
+        let b = range_end(&range, self.capacity());
+
+        match b.saturating_sub(a) {
+            0 => Some(&[]),
+            _ => match (a.cmp(&self.capacity()), b.cmp(&self.capacity())) {
+                (Ordering::Equal | Ordering::Greater, _) => None,
+                (_, Ordering::Greater) => None,
+                _ => {
+                    let slice = unsafe { core::slice::from_raw_parts(self.ptr_at(a), b - a) };
+                    Some(slice)
+                }
+            },
+        }
+    }
+
+    unsafe fn slices_mut<R: core::ops::RangeBounds<usize>>(&self, range: R) -> Option<&mut [T]> {
+        let a = range_start(&range);
+        let b = range_end(&range, self.capacity());
+
+        match b.saturating_sub(a) {
+            0 => Some(&mut []),
+            _ => match (a.cmp(&self.capacity()), b.cmp(&self.capacity())) {
+                (Ordering::Equal | Ordering::Greater, _) => None,
+                (_, Ordering::Greater) => None,
+                _ => {
+                    let slice = unsafe { core::slice::from_raw_parts_mut(self.ptr_at(a), b - a) };
+                    Some(slice)
+                }
+            },
+        }
+    }
+
+    unsafe fn iter<'a>(&'a self, len: usize) -> impl Iterator<Item = &'a T> + 'a
+    where
+        T: 'a,
+    {
+        let slice = unsafe { core::slice::from_raw_parts(self.ptr.as_ptr(), len) };
+        slice.iter()
+    }
+
+    unsafe fn iter_over_range<'a, R: core::ops::RangeBounds<usize>>(
+        &'a self,
+        range: R,
+    ) -> impl Iterator<Item = &'a T> + 'a
+    where
+        T: 'a,
+    {
+        let [a, b] = orx_pinned_vec::utils::slice::vec_range_limits(&range, None);
+        let slice = unsafe { core::slice::from_raw_parts(self.ptr_at(a), b - a) };
+        slice.iter()
+    }
+
+    unsafe fn iter_mut<'a>(&'a mut self, len: usize) -> impl Iterator<Item = &'a mut T> + 'a
+    where
+        T: 'a,
+    {
+        let slice = unsafe { core::slice::from_raw_parts_mut(self.ptr.as_ptr(), len) };
+        slice.iter_mut()
+    }
+
+    unsafe fn set_pinned_vec_len(&mut self, len: usize) {
+        self.vec.set_len_unchecked(len);
+    }
+
+    unsafe fn get(&self, index: usize) -> Option<&T> {
+        match index < self.capacity() {
+            true => Some(unsafe { &*self.ptr_at(index) }),
+            false => None,
+        }
+    }
+
+    unsafe fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        match index < self.capacity() {
+            true => Some(unsafe { &mut *self.ptr_at(index) }),
+            false => None,
+        }
+    }
+
+    unsafe fn get_ptr_mut(&self, index: usize) -> *mut T {
+        assert!(index < self.capacity());
+        self.ptr_at(index)
+    }
+
+    unsafe fn reserve_maximum_concurrent_capacity(&mut self, _: usize, _: usize) -> usize {
+        self.capacity()
+    }
+
+    unsafe fn reserve_maximum_concurrent_capacity_fill_with<F>(
+        &mut self,
+        _: usize,
+        _: usize,
+        _: F,
+    ) -> usize
+    where
+        F: Fn() -> T,
+    {
+        self.capacity()
+    }
+
+    unsafe fn clear(&mut self, _: usize) {
+        // elements are Pod, nothing to drop
+        self.vec.set_len_unchecked(0);
+    }
+
+    unsafe fn ptr_iter_unchecked(&self, range: Range<usize>) -> Self::PtrIter<'_> {
+        let ptr = unsafe { self.ptr.add(range.start) };
+        FixedVecPtrIter::new(ptr, range.len())
+    }
+
+    unsafe fn into_iter(self, range: Range<usize>) -> Self::IntoIter {
+        unsafe { MmapFixedVecIntoIter::new(self.vec, range) }
+    }
+}
+
+impl<T: Pod> IntoConcurrentPinnedVec<T> for MmapFixedVec<T> {
+    type ConPinnedVec = ConcurrentMmapFixedVec<T>;
+
+    fn into_concurrent(self) -> Self::ConPinnedVec {
+        self.into()
+    }
+
+    fn into_concurrent_filled_with<F>(mut self, fill_with: F) -> Self::ConPinnedVec
+    where
+        F: Fn() -> T,
+    {
+        for _ in self.len()..self.capacity() {
+            self.push_or_panic(fill_with());
+        }
+        self.into()
+    }
+}
@@ -0,0 +1,84 @@
+use super::MmapFixedVec;
+use bytemuck::Pod;
+use core::ops::Range;
+
+/// An iterator that moves out of a [`MmapFixedVec`].
+pub struct MmapFixedVecIntoIter<T: Pod> {
+    // keeps the mapping alive while the elements are copied out
+    vec: MmapFixedVec<T>,
+    current: usize,
+    end_exclusive: usize,
+}
+
+impl<T: Pod> MmapFixedVecIntoIter<T> {
+    /// # SAFETY
+    ///
+    /// Elements within `range` must be initialized.
+    pub(super) unsafe fn new(vec: MmapFixedVec<T>, range: Range<usize>) -> Self {
+        debug_assert!(range.end <= vec.capacity());
+        Self {
+            vec,
+            current: range.start,
+            end_exclusive: range.end,
+        }
+    }
+}
+
+impl<T: Pod> Iterator for MmapFixedVecIntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.current < self.end_exclusive {
+            true => {
+                // SAFETY: ptr + current is in bounds and is initialized
+                let value = unsafe { self.vec.ptr().add(self.current).read() };
+                self.current += 1;
+                Some(value)
+            }
+            false => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end_exclusive - self.current;
+        (len, Some(len))
+    }
+}
+
+impl<T: Pod> ExactSizeIterator for MmapFixedVecIntoIter<T> {
+    fn len(&self) -> usize {
+        self.end_exclusive - self.current
+    }
+}
+
+impl<T: Pod> IntoIterator for MmapFixedVec<T> {
+    type Item = T;
+    type IntoIter = MmapFixedVecIntoIter<T>;
+
+    /// Creates an iterator copying the elements out of the vector.
+    ///
+    /// Note that consuming the vector does not modify the mapped file.
+    fn into_iter(self) -> Self::IntoIter {
+        let range = 0..self.len();
+        // SAFETY: exactly the elements within 0..len are initialized
+        unsafe { MmapFixedVecIntoIter::new(self, range) }
+    }
+}
+
+impl<'a, T: Pod> IntoIterator for &'a MmapFixedVec<T> {
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_slice().iter()
+    }
+}
+
+impl<'a, T: Pod> IntoIterator for &'a mut MmapFixedVec<T> {
+    type Item = &'a mut T;
+    type IntoIter = core::slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_mut_slice().iter_mut()
+    }
+}
@@ -0,0 +1,439 @@
+use crate::FixedVecFullError;
+use bytemuck::Pod;
+use core::mem::{align_of, size_of};
+use core::ptr::NonNull;
+use memmap2::MmapMut;
+use std::fs::OpenOptions;
+use std::io;
+use std::path::Path;
+
+/// A memory mapped fixed vector, `MmapFixedVec<T>`, is a vector with a strict capacity which is decided at creation,
+/// whose elements are stored in a memory mapped file rather than in a heap allocated buffer as in [`FixedVec`](crate::FixedVec).
+///
+/// Available with the **mmap** feature.
+///
+/// # Pinned Elements
+///
+/// The mapping is never remapped or resized; hence, memory locations of the elements never change,
+/// and the vector can be moved freely while keeping the pinned element guarantees.
+/// [`PinnedVec<T>`](orx_pinned_vec::PinnedVec) and [`IntoConcurrentPinnedVec<T>`](orx_pinned_vec::IntoConcurrentPinnedVec)
+/// are implemented; therefore, concurrent collections can write directly into the mapped file.
+///
+/// # Persistence
+///
+/// The file starts with a small header holding the layout of `T`, the capacity and the length of the vector,
+/// followed by the elements. Since the length lives in the mapped header, every change to the vector is
+/// reflected in the file, and the vector can be reopened with [`open`](MmapFixedVec::open) without copying.
+/// Changes reach the operating system page cache immediately; [`flush`](MmapFixedVec::flush) can be used
+/// to durably store them on disk.
+///
+/// Elements are required to be [`Pod`] since they are persisted as plain bytes and any bit pattern read back
+/// from the file must be a valid `T`. The file is not portable across platforms with different endianness
+/// or different layouts of `T`.
+///
+/// The behavior is undefined if the file is modified by another process or by another mapping while it is
+/// mapped by the vector, as with any memory mapped file. Since this cannot be guaranteed by the vector,
+/// [`create`](MmapFixedVec::create) and [`open`](MmapFixedVec::open) are unsafe, and the caller is responsible
+/// for the exclusive access to the file.
+/// The length stored in the header is nevertheless never trusted beyond the capacity of the mapping.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_fixed_vec::prelude::*;
+/// use orx_fixed_vec::MmapFixedVec;
+///
+/// let path = std::env::temp_dir().join("orx-fixed-vec-doc-example.bin");
+///
+/// // SAFETY: the file is not accessed by any other mapping or process while mapped
+/// let mut vec = unsafe { MmapFixedVec::<u64>::create(&path, 4) }.unwrap();
+/// vec.push(42);
+/// vec.extend_from_slice(&[1, 2]);
+/// assert_eq!(vec.as_slice(), &[42, 1, 2]);
+/// drop(vec);
+///
+/// let vec = unsafe { MmapFixedVec::<u64>::open(&path) }.unwrap();
+/// assert_eq!(vec.capacity(), 4);
+/// assert_eq!(vec.as_slice(), &[42, 1, 2]);
+/// # drop(vec);
+/// # std::fs::remove_file(&path).unwrap();
+/// ```
+pub struct MmapFixedVec<T: Pod> {
+    // None only for the empty vector with zero capacity which does not map any memory
+    mapping: Option<Mapping>,
+    // dangling when there exists no mapping
+    data: NonNull<T>,
+    capacity: usize,
+}
+
+struct Mapping {
+    // keeps the mapping alive; memory is accessed only through the pointers of the vector
+    map: MmapMut,
+    header: NonNull<Header>,
+}
+
+// SAFETY: the vector exclusively owns the mapping and the elements.
+unsafe impl<T: Pod + Send> Send for MmapFixedVec<T> {}
+
+// SAFETY: only shared references to the elements are provided through a shared reference.
+unsafe impl<T: Pod + Sync> Sync for MmapFixedVec<T> {}
+
+impl<T: Pod> MmapFixedVec<T> {
+    /// Creates a new empty vector with the given fixed `capacity`, mapped to a file at the given `path`.
+    ///
+    /// The file is created if it does not exist, and it is truncated otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be created or mapped, or if the required file size overflows.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified, truncated or mapped by any other mapping, in this or any other process,
+    /// as long as the returned vector is alive; otherwise, multiple mutable references to the same memory
+    /// could be created.
+    pub unsafe fn create<P: AsRef<Path>>(path: P, capacity: usize) -> io::Result<Self> {
+        let map_len = map_len::<T>(capacity)?;
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(path)?;
+        file.set_len(map_len as u64)?;
+        // SAFETY: the file is freshly truncated, and the caller guarantees the exclusive access while mapped
+        let map = unsafe { MmapMut::map_mut(&file)? };
+        Ok(Self::init(map, capacity))
+    }
+
+    /// Opens the vector which is previously created by [`create`](MmapFixedVec::create) at the given `path`,
+    /// with its capacity and elements.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be opened or mapped, or if it does not contain a vector of elements
+    /// with the same size and alignment as `T`.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified, truncated or mapped by any other mapping, in this or any other process,
+    /// as long as the returned vector is alive; in particular, the same path must not be opened twice at the same time.
+    pub unsafe fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let file = OpenOptions::new().read(true).write(true).open(path)?;
+        // SAFETY: the caller guarantees the exclusive access to the file while mapped
+        let map = unsafe { MmapMut::map_mut(&file)? };
+        validate::<T>(&map)?;
+        Ok(Self::from_map(map))
+    }
+
+    /// Creates a new empty vector with the given fixed `capacity`, mapped to anonymous memory
+    /// which is not backed by a file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the memory cannot be mapped, or if the required size overflows.
+    pub fn new_anonymous(capacity: usize) -> io::Result<Self> {
+        let map = MmapMut::map_anon(map_len::<T>(capacity)?)?;
+        Ok(Self::init(map, capacity))
+    }
+
+    /// Creates an empty vector with zero capacity which does not map any memory; hence, it never fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_fixed_vec::MmapFixedVec;
+    ///
+    /// let mut vec = MmapFixedVec::<u32>::empty();
+    /// assert_eq!(vec.capacity(), 0);
+    /// assert!(vec.is_full());
+    /// assert!(vec.try_push(42).is_err());
+    /// ```
+    pub fn empty() -> Self {
+        Self {
+            mapping: None,
+            data: NonNull::dangling(),
+            capacity: 0,
+        }
+    }
+
+    /// Flushes the changes of the elements and the length to the file, returning once they are durably stored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the underlying system call fails.
+    pub fn flush(&self) -> io::Result<()> {
+        match &self.mapping {
+            Some(mapping) => mapping.map.flush(),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns the fixed capacity of the vector.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the available room for new items; i.e.,
+    /// `capacity() - len()`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_fixed_vec::prelude::*;
+    /// use orx_fixed_vec::MmapFixedVec;
+    ///
+    /// let mut vec = MmapFixedVec::new_anonymous(7).unwrap();
+    /// vec.push(42);
+    ///
+    /// assert_eq!(7, vec.capacity());
+    /// assert_eq!(1, vec.len());
+    /// assert_eq!(6, vec.room());
+    /// ```
+    pub fn room(&self) -> usize {
+        self.capacity - self.len()
+    }
+
+    /// Return whether the vector is full or not;
+    /// equivalent to `capacity() == len()` or `room() == 0`.
+    pub fn is_full(&self) -> bool {
+        self.len() == self.capacity
+    }
+
+    /// Extracts a slice containing the entire vector.
+    pub fn as_slice(&self) -> &[T] {
+        // SAFETY: elements within 0..len are initialized
+        unsafe { core::slice::from_raw_parts(self.ptr(), self.len()) }
+    }
+
+    /// Extracts a mutable slice containing the entire vector.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        // SAFETY: elements within 0..len are initialized
+        unsafe { core::slice::from_raw_parts_mut(self.ptr_mut(), self.len()) }
+    }
+
+    /// Appends an element to the back of the vector if there is available room;
+    /// returns back the `value` in a [`FixedVecFullError`] otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_fixed_vec::MmapFixedVec;
+    ///
+    /// let mut vec = MmapFixedVec::new_anonymous(1).unwrap();
+    ///
+    /// assert!(vec.try_push('a' as u32).is_ok());
+    ///
+    /// let error = vec.try_push('b' as u32).unwrap_err();
+    /// assert_eq!(error.into_rejected(), 'b' as u32);
+    /// ```
+    pub fn try_push(&mut self, value: T) -> Result<(), FixedVecFullError<T>> {
+        let len = self.len();
+        match self.is_full() {
+            true => Err(FixedVecFullError::new(value, self.capacity, len)),
+            false => {
+                // SAFETY: len < capacity
+                unsafe { self.ptr_mut().add(len).write(value) };
+                self.set_len_unchecked(len + 1);
+                Ok(())
+            }
+        }
+    }
+
+    // helpers
+
+    fn init(map: MmapMut, capacity: usize) -> Self {
+        const { assert!(align_of::<T>() <= HEADER_SIZE) };
+        let header = Header {
+            magic: MAGIC,
+            element_size: size_of::<T>() as u64,
+            element_align: align_of::<T>() as u64,
+            capacity: capacity as u64,
+            len: 0,
+        };
+        let (mapping, data) = Self::from_map_with_capacity(map, capacity);
+        // SAFETY: the mapping is at least HEADER_SIZE bytes long and page aligned
+        unsafe { mapping.header.as_ptr().write(header) };
+        Self {
+            mapping: Some(mapping),
+            data,
+            capacity,
+        }
+    }
+
+    fn from_map(map: MmapMut) -> Self {
+        // SAFETY: header is validated
+        let capacity = unsafe { (map.as_ptr() as *const Header).read() }.capacity as usize;
+        let (mapping, data) = Self::from_map_with_capacity(map, capacity);
+        Self {
+            mapping: Some(mapping),
+            data,
+            capacity,
+        }
+    }
+
+    fn from_map_with_capacity(mut map: MmapMut, capacity: usize) -> (Mapping, NonNull<T>) {
+        const { assert!(align_of::<T>() <= HEADER_SIZE) };
+        debug_assert!(map.len() >= HEADER_SIZE + capacity * size_of::<T>());
+        let base = map.as_mut_ptr();
+        // SAFETY: pointer of a mapping is never null, and data starts within the mapping
+        let header = unsafe { NonNull::new_unchecked(base as *mut Header) };
+        let data = unsafe { NonNull::new_unchecked(base.add(HEADER_SIZE) as *mut T) };
+        (Mapping { map, header }, data)
+    }
+
+    #[inline(always)]
+    pub(super) fn len(&self) -> usize {
+        match &self.mapping {
+            Some(mapping) => {
+                // SAFETY: header is valid for the lifetime of the mapping
+                let len = unsafe { (*mapping.header.as_ptr()).len };
+                // the header is within the file; it is never trusted to exceed the capacity of the mapping
+                usize::try_from(len).map_or(self.capacity, |len| len.min(self.capacity))
+            }
+            None => 0,
+        }
+    }
+
+    #[inline(always)]
+    pub(super) fn set_len_unchecked(&mut self, len: usize) {
+        debug_assert!(len <= self.capacity);
+        if let Some(mapping) = &self.mapping {
+            // SAFETY: header is valid for the lifetime of the mapping, `&mut self` guarantees exclusive access
+            unsafe { (*mapping.header.as_ptr()).len = len as u64 };
+        }
+    }
+
+    #[inline(always)]
+    pub(crate) fn ptr(&self) -> *const T {
+        self.data.as_ptr()
+    }
+
+    #[inline(always)]
+    pub(crate) fn ptr_mut(&mut self) -> *mut T {
+        self.data.as_ptr()
+    }
+
+    #[inline(always)]
+    pub(super) fn data_ptr(&self) -> NonNull<T> {
+        self.data
+    }
+
+    #[inline(always)]
+    pub(crate) fn panic_if_not_enough_room_for(&self, num_new_items: usize) {
+        assert!(
+            self.len() + num_new_items <= self.capacity,
+            "{}",
+            ERR_MSG_OUT_OF_ROOM
+        );
+    }
+
+    #[inline(always)]
+    pub(crate) fn push_or_panic(&mut self, value: T) {
+        let len = self.len();
+        assert!(len < self.capacity, "{}", ERR_MSG_OUT_OF_ROOM);
+        // SAFETY: len < capacity
+        unsafe { self.ptr_mut().add(len).write(value) };
+        self.set_len_unchecked(len + 1);
+    }
+
+    pub(crate) fn insert_or_panic(&mut self, index: usize, element: T) {
+        let len = self.len();
+        assert!(index <= len, "insertion index is out of bounds");
+        self.panic_if_not_enough_room_for(1);
+        let p = unsafe { self.ptr_mut().add(index) };
+        // SAFETY: index <= len < capacity; hence, shifting `len - index` elements to the right stays in bounds
+        unsafe { core::ptr::copy(p, p.add(1), len - index) };
+        unsafe { p.write(element) };
+        self.set_len_unchecked(len + 1);
+    }
+
+    pub(crate) fn remove_or_panic(&mut self, index: usize) -> T {
+        let len = self.len();
+        assert!(index < len, "removal index is out of bounds");
+        let p = unsafe { self.ptr_mut().add(index) };
+        // SAFETY: index < len; value is copied out and the gap is closed by shifting the tail to the left
+        let value = unsafe { p.read() };
+        unsafe { core::ptr::copy(p.add(1), p, len - index - 1) };
+        self.set_len_unchecked(len - 1);
+        value
+    }
+
+    pub(crate) fn pop_back(&mut self) -> Option<T> {
+        match self.len() {
+            0 => None,
+            len => {
+                self.set_len_unchecked(len - 1);
+                // SAFETY: the element at the previous last position is initialized
+                Some(unsafe { self.ptr().add(len - 1).read() })
+            }
+        }
+    }
+
+    pub(crate) fn truncate_to(&mut self, len: usize) {
+        // elements are Pod, nothing to drop
+        if len < self.len() {
+            self.set_len_unchecked(len);
+        }
+    }
+}
+
+// header
+
+/// Header at the beginning of the mapping.
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct Header {
+    magic: [u8; 8],
+    element_size: u64,
+    element_align: u64,
+    capacity: u64,
+    len: u64,
+}
+
+/// Size reserved for the header; elements start at this offset of the page aligned mapping,
+/// which is sufficient for element types with alignment up to 64.
+const HEADER_SIZE: usize = 64;
+
+const MAGIC: [u8; 8] = *b"ORXFXVEC";
+
+pub(super) const ERR_MSG_MAP_ANON: &str = "failed to create an anonymous memory mapping";
+
+const ERR_MSG_OUT_OF_ROOM: &str =
+    "MmapFixedVec is full, a fixed capacity vector cannot exceed its capacity.";
+
+fn map_len<T>(capacity: usize) -> io::Result<usize> {
+    capacity
+        .checked_mul(size_of::<T>())
+        .and_then(|x| x.checked_add(HEADER_SIZE))
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "capacity overflow"))
+}
+
+fn validate<T>(map: &MmapMut) -> io::Result<()> {
+    let invalid = |msg: &str| io::Error::new(io::ErrorKind::InvalidData, msg);
+
+    if map.len() < HEADER_SIZE {
+        return Err(invalid("file is too short to be a MmapFixedVec"));
+    }
+    // SAFETY: mapping is at least HEADER_SIZE bytes long and page aligned; any bit pattern is a valid header
+    let header = unsafe { (map.as_ptr() as *const Header).read() };
+
+    if header.magic != MAGIC {
+        return Err(invalid("file is not a MmapFixedVec"));
+    }
+    if header.element_size != size_of::<T>() as u64
+        || header.element_align != align_of::<T>() as u64
+    {
+        return Err(invalid(
+            "layout of the elements does not match the element type",
+        ));
+    }
+    let capacity = usize::try_from(header.capacity).map_err(|_| invalid("capacity overflow"))?;
+    if map_len::<T>(capacity)? > map.len() {
+        return Err(invalid("file is shorter than the capacity of the vector"));
+    }
+    if header.len > header.capacity {
+        return Err(invalid("length of the vector exceeds its capacity"));
+    }
+    Ok(())
+}
@@ -0,0 +1,12 @@
+mod common_traits;
+mod con_mmap_fixed_vec;
+mod into_iter;
+mod mmap_vec;
+mod pinned_vec;
+
+#[cfg(test)]
+mod tests;
+
+pub use con_mmap_fixed_vec::ConcurrentMmapFixedVec;
+pub use into_iter::MmapFixedVecIntoIter;
+pub use mmap_vec::MmapFixedVec;
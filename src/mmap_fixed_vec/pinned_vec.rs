@@ -0,0 +1,396 @@
+use super::MmapFixedVec;
+use crate::helpers::range::{range_end, range_start};
+use bytemuck::Pod;
+use core::cmp::Ordering;
+use core::iter::Rev;
+use core::ops::RangeBounds;
+use orx_pinned_vec::utils::slice;
+use orx_pinned_vec::{CapacityState, PinnedVec};
+use orx_pseudo_default::PseudoDefault;
+
+impl<T: Pod> PseudoDefault for MmapFixedVec<T> {
+    /// Creates an empty vector with zero capacity without mapping any memory; see [`MmapFixedVec::empty`].
+    fn pseudo_default() -> Self {
+        MmapFixedVec::empty()
+    }
+}
+
+impl<T: Pod> PinnedVec<T> for MmapFixedVec<T> {
+    type IterRev<'a>
+        = Rev<core::slice::Iter<'a, T>>
+    where
+        T: 'a,
+        Self: 'a;
+
+    type IterMutRev<'a>
+        = Rev<core::slice::IterMut<'a, T>>
+    where
+        T: 'a,
+        Self: 'a;
+
+    type SliceIter<'a>
+        = Option<&'a [T]>
+    where
+        T: 'a,
+        Self: 'a;
+
+    type SliceMutIter<'a>
+        = Option<&'a mut [T]>
+    where
+        T: 'a,
+        Self: 'a;
+
+    /// Returns the index of the `element` with the given reference.
+    /// This method has *O(1)* time complexity.
+    ///
+    /// Note that `T: Eq` is not required; reference equality is used.
+    ///
+    /// # Safety
+    ///
+    /// Since `MmapFixedVec` implements `PinnedVec`, the underlying memory
+    /// of the vector stays pinned; i.e., is not carried to different memory
+    /// locations.
+    /// Therefore, it is possible and safe to compare an element's reference
+    /// to find its position in the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_fixed_vec::prelude::*;
+    /// use orx_fixed_vec::MmapFixedVec;
+    ///
+    /// let mut vec = MmapFixedVec::<u32>::new_anonymous(4).unwrap();
+    /// for i in 0..4 {
+    ///     vec.push(10 * i);
+    /// }
+    ///
+    /// assert_eq!(Some(0), vec.index_of(&vec[0]));
+    /// assert_eq!(Some(3), vec.index_of(&vec[3]));
+    ///
+    /// // even if its value belongs
+    /// let num = 20;
+    /// assert_eq!(None, vec.index_of(&num));
+    /// ```
+    #[inline(always)]
+    fn index_of(&self, element: &T) -> Option<usize> {
+        slice::index_of(self.as_slice(), element)
+    }
+
+    fn index_of_ptr(&self, element_ptr: *const T) -> Option<usize> {
+        slice::index_of_ptr(self.as_slice(), element_ptr)
+    }
+
+    fn push_get_ptr(&mut self, value: T) -> *const T {
+        let idx = self.len();
+        self.push_or_panic(value);
+        unsafe { self.ptr().add(idx) }
+    }
+
+    unsafe fn iter_ptr<'v, 'i>(&'v self) -> impl Iterator<Item = *const T> + 'i
+    where
+        T: 'i,
+    {
+        let ptr = self.ptr();
+        (0..self.len()).map(move |i| unsafe { ptr.add(i) })
+    }
+
+    unsafe fn iter_ptr_rev<'v, 'i>(&'v self) -> impl Iterator<Item = *const T> + 'i
+    where
+        T: 'i,
+    {
+        let ptr = self.ptr();
+        (0..self.len()).rev().map(move |i| unsafe { ptr.add(i) })
+    }
+
+    /// Returns whether or not the `element` with the given reference belongs to the vector.
+    /// This method has *O(1)* time complexity.
+    ///
+    /// Note that `T: Eq` is not required; memory address is used.
+    #[inline(always)]
+    fn contains_reference(&self, element: &T) -> bool {
+        slice::contains_reference(self.as_slice(), element)
+    }
+
+    /// Returns whether or not the `element` with the given pointer belongs to the vector.
+    /// This method has *O(1)* time complexity.
+    ///
+    /// Note that `T: Eq` is not required; memory pointer is used.
+    #[inline(always)]
+    fn contains_ptr(&self, element_ptr: *const T) -> bool {
+        slice::contains_ptr(self.as_slice(), element_ptr)
+    }
+
+    fn clear(&mut self) {
+        self.truncate_to(0);
+    }
+
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+
+    fn capacity_state(&self) -> CapacityState {
+        CapacityState::FixedCapacity(self.capacity())
+    }
+
+    /// Copies and appends all elements in a slice to the vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is not enough room in the vector for the elements in `other`;
+    /// i.e., `self.room() < other.len()`.
+    fn extend_from_slice(&mut self, other: &[T])
+    where
+        T: Clone,
+    {
+        self.panic_if_not_enough_room_for(other.len());
+        for x in other {
+            self.push_or_panic(*x);
+        }
+    }
+
+    /// Extends this vector by copying `count` * `size_of::<T>()` bytes from `src` to the end of the vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is not enough room in the vector for `count` elements;
+    /// i.e., `self.room() < count`.
+    ///
+    /// # Safety
+    ///
+    /// Behavior is undefined if any of the following conditions are violated:
+    ///
+    /// * `src` must be valid for reads of `count * size_of::<T>()` bytes.
+    /// * `src` must be properly aligned.
+    /// * The region of memory beginning at `src` with a size of `count * size_of::<T>()`
+    ///   bytes must *not* overlap with the region of memory of this vector.
+    unsafe fn extend_from_nonoverlapping(&mut self, src: *const T, count: usize) {
+        self.panic_if_not_enough_room_for(count);
+        let len = self.len();
+        let dst = unsafe { self.ptr_mut().add(len) };
+        unsafe { dst.copy_from_nonoverlapping(src, count) };
+        self.set_len_unchecked(len + count);
+    }
+
+    #[inline(always)]
+    fn get(&self, index: usize) -> Option<&T> {
+        self.as_slice().get(index)
+    }
+
+    #[inline(always)]
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.as_mut_slice().get_mut(index)
+    }
+
+    #[inline(always)]
+    unsafe fn get_unchecked(&self, index: usize) -> &T {
+        unsafe { self.as_slice().get_unchecked(index) }
+    }
+
+    #[inline(always)]
+    unsafe fn get_unchecked_mut(&mut self, index: usize) -> &mut T {
+        unsafe { self.as_mut_slice().get_unchecked_mut(index) }
+    }
+
+    #[inline(always)]
+    fn first(&self) -> Option<&T> {
+        self.as_slice().first()
+    }
+
+    #[inline(always)]
+    fn last(&self) -> Option<&T> {
+        self.as_slice().last()
+    }
+
+    #[inline(always)]
+    unsafe fn first_unchecked(&self) -> &T {
+        unsafe { self.as_slice().get_unchecked(0) }
+    }
+
+    #[inline(always)]
+    unsafe fn last_unchecked(&self) -> &T {
+        unsafe { self.as_slice().get_unchecked(self.len() - 1) }
+    }
+
+    #[inline(always)]
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    /// Appends an element to the back of a collection.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no available room in the vector;
+    /// i.e., `self.is_full()` or equivalently `self.len() == self.capacity()`.
+    #[inline(always)]
+    fn push(&mut self, value: T) {
+        self.push_or_panic(value)
+    }
+
+    /// Inserts an element at position index within the vector, shifting all elements after it to the right.
+    ///
+    /// # Panics
+    /// Panics if `index > len`.
+    ///
+    /// Panics also if there is no available room in the vector;
+    /// i.e., `self.is_full()` or equivalently `self.len() == self.capacity()`.
+    #[inline(always)]
+    fn insert(&mut self, index: usize, element: T) {
+        self.insert_or_panic(index, element)
+    }
+
+    /// Removes and returns the element at position index within the vector, shifting all elements after it to the left.
+    ///
+    /// # Panics
+    /// Panics if `index >= len`.
+    #[inline(always)]
+    fn remove(&mut self, index: usize) -> T {
+        self.remove_or_panic(index)
+    }
+
+    #[inline(always)]
+    fn pop(&mut self) -> Option<T> {
+        self.pop_back()
+    }
+
+    #[inline(always)]
+    fn swap(&mut self, a: usize, b: usize) {
+        self.as_mut_slice().swap(a, b)
+    }
+
+    #[inline(always)]
+    fn truncate(&mut self, len: usize) {
+        self.truncate_to(len)
+    }
+
+    #[inline(always)]
+    fn iter_rev(&self) -> Self::IterRev<'_> {
+        self.as_slice().iter().rev()
+    }
+
+    #[inline(always)]
+    fn iter_mut_rev(&mut self) -> Self::IterMutRev<'_> {
+        self.as_mut_slice().iter_mut().rev()
+    }
+
+    /// Returns the view on the required `range` as an Option of slice:
+    ///
+    /// * returns None if the range is out of bounds;
+    /// * returns Some of the slice when the range is within bounds of the vector.
+    fn slices<R: RangeBounds<usize>>(&self, range: R) -> Self::SliceIter<'_> {
+        let a = range_start(&range);
+        let b = range_end(&range, self.len());
+
+        match b.saturating_sub(a) {
+            0 => Some(&[]),
+            _ => match (a.cmp(&self.len()), b.cmp(&self.len())) {
+                (Ordering::Equal | Ordering::Greater, _) => None,
+                (_, Ordering::Greater) => None,
+                _ => Some(&self.as_slice()[a..b]),
+            },
+        }
+    }
+
+    /// Returns a mutable view on the required `range` as an Option of slice:
+    ///
+    /// * returns None if the range is out of bounds;
+    /// * returns Some of the slice when the range is within bounds of the vector.
+    fn slices_mut<R: RangeBounds<usize>>(&mut self, range: R) -> Self::SliceMutIter<'_> {
+        let a = range_start(&range);
+        let b = range_end(&range, self.len());
+
+        match b.saturating_sub(a) {
+            0 => Some(&mut []),
+            _ => match (a.cmp(&self.len()), b.cmp(&self.len())) {
+                (Ordering::Equal | Ordering::Greater, _) => None,
+                (_, Ordering::Greater) => None,
+                _ => Some(&mut self.as_mut_slice()[a..b]),
+            },
+        }
+    }
+
+    fn iter_over<'a>(
+        &'a self,
+        range: impl RangeBounds<usize>,
+    ) -> impl ExactSizeIterator<Item = &'a T>
+    where
+        T: 'a,
+    {
+        use core::cmp::{max, min};
+
+        let len = self.len();
+        let a = min(len, range_start(&range));
+        let b = max(a, min(len, range_end(&range, len)));
+
+        self.as_slice()[a..b].iter()
+    }
+
+    fn iter_mut_over<'a>(
+        &'a mut self,
+        range: impl RangeBounds<usize>,
+    ) -> impl ExactSizeIterator<Item = &'a mut T>
+    where
+        T: 'a,
+    {
+        use core::cmp::{max, min};
+
+        let len = self.len();
+        let a = min(len, range_start(&range));
+        let b = max(a, min(len, range_end(&range, len)));
+
+        self.as_mut_slice()[a..b].iter_mut()
+    }
+
+    #[inline(always)]
+    fn get_ptr(&self, index: usize) -> Option<*const T> {
+        (index < self.capacity()).then(|| unsafe { self.ptr().add(index) })
+    }
+
+    #[inline(always)]
+    fn get_ptr_mut(&mut self, index: usize) -> Option<*mut T> {
+        (index < self.capacity()).then(|| unsafe { self.ptr_mut().add(index) })
+    }
+
+    #[inline(always)]
+    unsafe fn set_len(&mut self, new_len: usize) {
+        self.set_len_unchecked(new_len);
+    }
+
+    fn binary_search_by<F>(&self, f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        self.as_slice().binary_search_by(f)
+    }
+
+    fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.as_mut_slice().sort();
+    }
+
+    fn sort_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.as_mut_slice().sort_by(compare)
+    }
+
+    fn sort_by_key<K, F>(&mut self, f: F)
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        self.as_mut_slice().sort_by_key(f)
+    }
+
+    fn capacity_bound(&self) -> usize {
+        self.capacity()
+    }
+}
@@ -0,0 +1,108 @@
+use super::TempFile;
+use crate::MmapFixedVec;
+use crate::prelude::*;
+use std::vec::Vec;
+
+#[test]
+fn concurrent_writes_are_persisted() {
+    let file = TempFile::new("concurrent-writes");
+    let num_threads = 4;
+    let chunk = 64;
+    let capacity = num_threads * chunk;
+
+    let mut vec = unsafe { MmapFixedVec::<u64>::create(file.path(), capacity) }.expect("created");
+    vec.push(u64::MAX);
+    let con_vec = vec.into_concurrent();
+
+    std::thread::scope(|s| {
+        for t in 0..num_threads {
+            let con_vec = &con_vec;
+            s.spawn(move || {
+                for i in (t * chunk).max(1)..(t + 1) * chunk {
+                    unsafe { con_vec.get_ptr_mut(i).write(i as u64) };
+                }
+            });
+        }
+    });
+
+    let vec = unsafe { con_vec.into_inner(capacity) };
+    assert!(vec.is_full());
+    drop(vec);
+
+    let vec = unsafe { MmapFixedVec::<u64>::open(file.path()) }.expect("opened");
+    assert_eq!(vec.len(), capacity);
+    assert_eq!(vec[0], u64::MAX);
+    assert!((1..capacity).all(|i| vec[i] == i as u64));
+}
+
+#[test]
+fn into_concurrent_fill_with() {
+    let mut vec = MmapFixedVec::new_anonymous(42).expect("mapped");
+    vec.push(7u32);
+    let con_vec = vec.into_concurrent_filled_with(|| 1);
+    let vec = unsafe { con_vec.into_inner(42) };
+    assert_eq!(vec[0], 7);
+    assert!(vec[1..].iter().all(|x| *x == 1));
+}
+
+#[test]
+fn grow_and_reserve() {
+    let vec = MmapFixedVec::<u32>::new_anonymous(16).expect("mapped");
+    let mut con_vec = vec.into_concurrent();
+
+    assert_eq!(con_vec.grow_to(12), Ok(16));
+    assert!(con_vec.grow_to(17).is_err());
+    assert_eq!(
+        unsafe { con_vec.reserve_maximum_concurrent_capacity(0, 32) },
+        16
+    );
+    assert_eq!(con_vec.capacity(), 16);
+}
+
+#[test]
+fn slices_iter_and_clone() {
+    let mut vec = MmapFixedVec::new_anonymous(7).expect("mapped");
+    vec.extend_from_slice(&[0, 1, 2, 3, 4, 5, 6]);
+    let con_vec = vec.into_concurrent();
+
+    assert_eq!(con_vec.slices(1..4), Some(&[1, 2, 3][..]));
+    assert_eq!(con_vec.slices(4..8), None);
+
+    unsafe {
+        let vec: Vec<_> = con_vec.iter(5).copied().collect();
+        assert_eq!(vec, &[0, 1, 2, 3, 4]);
+
+        let vec: Vec<_> = con_vec.iter_over_range(2..=4).copied().collect();
+        assert_eq!(vec, &[2, 3, 4]);
+
+        let ptrs: Vec<_> = con_vec.ptr_iter_unchecked(3..6).collect();
+        assert_eq!(ptrs.iter().map(|p| **p).collect::<Vec<_>>(), &[3, 4, 5]);
+    }
+
+    let clone = unsafe { con_vec.clone_with_len(3) };
+    let clone = unsafe { clone.into_inner(3) };
+    assert_eq!(clone, [0, 1, 2]);
+    assert_eq!(clone.capacity(), 7);
+}
+
+#[test]
+fn into_iter_range_and_clear() {
+    let mut vec = MmapFixedVec::new_anonymous(20).expect("mapped");
+    vec.extend_from_slice(&(0..20).collect::<Vec<u32>>());
+
+    let con_vec = vec.into_concurrent();
+    let mut iter = unsafe { con_vec.into_iter(4..15) };
+    assert_eq!(iter.len(), 11);
+    assert_eq!(iter.next(), Some(4));
+    assert_eq!(iter.collect::<Vec<_>>(), (5..15).collect::<Vec<_>>());
+
+    let file = TempFile::new("clear");
+    let mut vec = unsafe { MmapFixedVec::<u32>::create(file.path(), 4) }.expect("created");
+    vec.extend_from_slice(&[1, 2, 3]);
+    let mut con_vec = vec.into_concurrent();
+    unsafe { con_vec.clear(3) };
+    drop(con_vec);
+
+    let vec = unsafe { MmapFixedVec::<u32>::open(file.path()) }.expect("opened");
+    assert!(vec.is_empty());
+}
@@ -0,0 +1,177 @@
+use super::TempFile;
+use crate::MmapFixedVec;
+use crate::prelude::*;
+use bytemuck::{Pod, Zeroable};
+use std::io::ErrorKind;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(C)]
+struct Point {
+    x: f32,
+    y: f32,
+}
+
+// SAFETY: all bit patterns are valid, and there is no padding
+unsafe impl Zeroable for Point {}
+unsafe impl Pod for Point {}
+
+#[test]
+fn create_push_reopen() {
+    let file = TempFile::new("create-push-reopen");
+
+    let mut vec = unsafe { MmapFixedVec::<u64>::create(file.path(), 10) }.expect("created");
+    assert_eq!(vec.capacity(), 10);
+    assert!(vec.is_empty());
+
+    for i in 0..6 {
+        vec.push(i * 10);
+    }
+    vec.insert(0, 7);
+    assert_eq!(vec.remove(3), 20);
+    assert_eq!(vec.pop(), Some(50));
+    vec.flush().expect("flushed");
+    drop(vec);
+
+    let mut vec = unsafe { MmapFixedVec::<u64>::open(file.path()) }.expect("opened");
+    assert_eq!(vec.capacity(), 10);
+    assert_eq!(vec.room(), 5);
+    assert_eq!(vec, [7, 0, 10, 30, 40]);
+
+    vec.truncate(2);
+    drop(vec);
+    let vec = unsafe { MmapFixedVec::<u64>::open(file.path()) }.expect("opened");
+    assert_eq!(vec, [7, 0]);
+}
+
+#[test]
+fn create_truncates_existing_file() {
+    let file = TempFile::new("create-truncates");
+
+    let mut vec = unsafe { MmapFixedVec::<u32>::create(file.path(), 4) }.expect("created");
+    vec.extend_from_slice(&[1, 2, 3]);
+    drop(vec);
+
+    let vec = unsafe { MmapFixedVec::<u32>::create(file.path(), 2) }.expect("created");
+    assert_eq!(vec.capacity(), 2);
+    assert!(vec.is_empty());
+}
+
+#[test]
+fn pod_structs() {
+    let file = TempFile::new("pod-structs");
+
+    let mut vec = unsafe { MmapFixedVec::<Point>::create(file.path(), 3) }.expect("created");
+    vec.push(Point { x: 1.0, y: 2.0 });
+    vec[0].y = 3.0;
+    drop(vec);
+
+    let vec = unsafe { MmapFixedVec::<Point>::open(file.path()) }.expect("opened");
+    assert_eq!(vec.as_slice(), &[Point { x: 1.0, y: 3.0 }]);
+}
+
+#[test]
+fn open_rejects_invalid_files() {
+    let file = TempFile::new("open-rejects");
+
+    let mut vec = unsafe { MmapFixedVec::<u32>::create(file.path(), 4) }.expect("created");
+    vec.push(42);
+    drop(vec);
+
+    // element layout mismatch
+    let error = unsafe { MmapFixedVec::<u64>::open(file.path()) }.expect_err("invalid");
+    assert_eq!(error.kind(), ErrorKind::InvalidData);
+    let error = unsafe { MmapFixedVec::<[u8; 4]>::open(file.path()) }.expect_err("invalid");
+    assert_eq!(error.kind(), ErrorKind::InvalidData);
+
+    // not a vector
+    std::fs::write(file.path(), [0u8; 128]).expect("written");
+    let error = unsafe { MmapFixedVec::<u32>::open(file.path()) }.expect_err("invalid");
+    assert_eq!(error.kind(), ErrorKind::InvalidData);
+
+    // too short
+    std::fs::write(file.path(), [0u8; 8]).expect("written");
+    let error = unsafe { MmapFixedVec::<u32>::open(file.path()) }.expect_err("invalid");
+    assert_eq!(error.kind(), ErrorKind::InvalidData);
+
+    // missing
+    drop(file);
+    let file = TempFile::new("open-rejects-missing");
+    let error = unsafe { MmapFixedVec::<u32>::open(file.path()) }.expect_err("invalid");
+    assert_eq!(error.kind(), ErrorKind::NotFound);
+}
+
+#[test]
+fn open_rejects_truncated_file() {
+    let file = TempFile::new("open-rejects-truncated");
+
+    let vec = unsafe { MmapFixedVec::<u32>::create(file.path(), 100) }.expect("created");
+    drop(vec);
+
+    let f = std::fs::OpenOptions::new()
+        .write(true)
+        .open(file.path())
+        .expect("opened");
+    f.set_len(100).expect("truncated");
+
+    let error = unsafe { MmapFixedVec::<u32>::open(file.path()) }.expect_err("invalid");
+    assert_eq!(error.kind(), ErrorKind::InvalidData);
+}
+
+#[test]
+fn capacity_overflow() {
+    let error = MmapFixedVec::<u64>::new_anonymous(usize::MAX).expect_err("overflow");
+    assert_eq!(error.kind(), ErrorKind::InvalidInput);
+}
+
+#[test]
+fn try_push_when_full() {
+    let mut vec = MmapFixedVec::<u8>::new_anonymous(2).expect("mapped");
+    assert!(vec.try_push(1).is_ok());
+    assert!(vec.try_push(2).is_ok());
+    assert!(vec.is_full());
+
+    let error = vec.try_push(3).expect_err("is-full");
+    assert_eq!(error.capacity(), 2);
+    assert_eq!(error.len(), 2);
+    assert_eq!(error.into_rejected(), 3);
+}
+
+#[test]
+#[should_panic]
+fn push_when_full() {
+    let mut vec = MmapFixedVec::<u8>::new_anonymous(1).expect("mapped");
+    vec.push(1);
+    vec.push(2);
+}
+
+#[test]
+fn zero_sized_elements() {
+    let mut vec = MmapFixedVec::<()>::new_anonymous(3).expect("mapped");
+    vec.push(());
+    vec.push(());
+    assert_eq!(vec.len(), 2);
+    assert_eq!(vec.into_iter().count(), 2);
+}
+
+#[test]
+fn len_is_clamped_to_capacity() {
+    use std::io::{Seek, SeekFrom, Write};
+
+    let file = TempFile::new("len-is-clamped");
+    let mut vec = unsafe { MmapFixedVec::<u32>::create(file.path(), 4) }.expect("created");
+    vec.extend_from_slice(&[1, 2]);
+
+    // length field of the header is overwritten externally while mapped
+    let mut f = std::fs::OpenOptions::new()
+        .write(true)
+        .open(file.path())
+        .expect("opened");
+    f.seek(SeekFrom::Start(32)).expect("seeked");
+    f.write_all(&1000u64.to_ne_bytes()).expect("written");
+    drop(f);
+
+    assert_eq!(vec.len(), 4);
+    assert_eq!(vec.as_slice().len(), 4);
+    assert_eq!(vec.as_mut_slice().len(), 4);
+    assert_eq!(vec.room(), 0);
+}
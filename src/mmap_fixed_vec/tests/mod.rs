@@ -0,0 +1,25 @@
+mod con_mmap_fixed_vec;
+mod mmap_vec;
+mod pinned_vec;
+
+use std::path::{Path, PathBuf};
+
+/// Path to a file in the temp directory which is removed when dropped.
+struct TempFile(PathBuf);
+
+impl TempFile {
+    fn new(name: &str) -> Self {
+        let file_name = std::format!("orx-fixed-vec-{}-{}.bin", name, std::process::id());
+        Self(std::env::temp_dir().join(file_name))
+    }
+
+    fn path(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.0);
+    }
+}
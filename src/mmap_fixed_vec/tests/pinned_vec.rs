@@ -0,0 +1,84 @@
+use super::TempFile;
+use crate::MmapFixedVec;
+use orx_pinned_vec::*;
+use orx_pseudo_default::PseudoDefault;
+use std::vec::Vec;
+
+#[test]
+fn pinned_vec_anonymous() {
+    for cap in [0, 10, 124] {
+        test_pinned_vec(MmapFixedVec::new_anonymous(cap).expect("mapped"), cap);
+    }
+    test_pinned_vec(MmapFixedVec::new_anonymous(88).expect("mapped"), 44);
+}
+
+#[test]
+fn pinned_vec_file() {
+    let file = TempFile::new("pinned-vec-file");
+    test_pinned_vec(
+        unsafe { MmapFixedVec::create(file.path(), 124) }.expect("created"),
+        124,
+    );
+}
+
+#[test]
+fn pinned_on_move() {
+    let mut vec = MmapFixedVec::new_anonymous(100).expect("mapped");
+    vec.push(42usize);
+    let addr42 = &vec[0] as *const usize;
+
+    let mut vecs = Vec::new();
+    vecs.push(vec);
+    let mut vec = vecs.pop().expect("is-some");
+
+    for i in 1..100 {
+        vec.push(i);
+    }
+
+    assert_eq!(addr42, &vec[0] as *const usize);
+    assert_eq!(vec.index_of(unsafe { &*addr42 }), Some(0));
+}
+
+#[test]
+fn capacity_and_pseudo_default() {
+    let vec = MmapFixedVec::<u32>::new_anonymous(44).expect("mapped");
+    assert_eq!(44, PinnedVec::capacity(&vec));
+    assert_eq!(CapacityState::FixedCapacity(44), vec.capacity_state());
+
+    let vec = MmapFixedVec::<u32>::pseudo_default();
+    assert_eq!(0, PinnedVec::capacity(&vec));
+    assert!(vec.is_empty());
+}
+
+#[test]
+fn empty_without_mapping() {
+    test_pinned_vec(MmapFixedVec::<usize>::empty(), 0);
+
+    let mut vec = MmapFixedVec::<u32>::empty();
+    assert!(vec.flush().is_ok());
+    assert!(vec.as_slice().is_empty());
+    assert_eq!(vec.try_push(1).map_err(|e| e.into_rejected()), Err(1));
+    vec.clear();
+    assert_eq!(vec.pop(), None);
+
+    let con_vec = vec.into_concurrent();
+    let vec = unsafe { con_vec.into_inner(0) };
+    assert_eq!(vec.capacity(), 0);
+}
+
+#[test]
+fn iterators() {
+    let mut vec = MmapFixedVec::new_anonymous(8).expect("mapped");
+    vec.extend_from_slice(&[3, 1, 2]);
+    vec.sort();
+
+    for x in &mut vec {
+        *x *= 10;
+    }
+    assert_eq!(
+        (&vec).into_iter().copied().collect::<Vec<_>>(),
+        [10, 20, 30]
+    );
+    assert_eq!(vec.iter_rev().copied().collect::<Vec<_>>(), [30, 20, 10]);
+    assert_eq!(vec.into_iter().collect::<Vec<_>>(), [10, 20, 30]);
+}
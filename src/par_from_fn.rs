@@ -0,0 +1,196 @@
+use crate::{ConcurrentFixedVec, FixedVec};
+use core::ops::Range;
+use orx_pinned_vec::ConcurrentPinnedVec;
+use std::thread;
+use std::vec::Vec;
+
+impl<T: Send> FixedVec<T> {
+    /// Creates a fixed vector with the given `fixed_capacity` which is filled up to its capacity
+    /// such that the element at position `i` is `f(i)`, where the elements are created in parallel.
+    ///
+    /// The index range `0..fixed_capacity` is split into `num_threads` contiguous chunks of (almost) equal lengths,
+    /// each of which is written directly into the buffer by a scoped thread through a [`ConcurrentFixedVec`].
+    /// `num_threads` is treated as 1 if it is zero, and it is bounded by `fixed_capacity`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the required memory exceeds `isize::MAX` bytes.
+    ///
+    /// If `f` panics on any of the threads, the panic is propagated to the caller once all threads complete.
+    /// In this case, all elements that have been created so far are dropped; hence, no element is leaked.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_fixed_vec::prelude::*;
+    ///
+    /// let vec = FixedVec::par_from_fn(1000, 4, |i| i * 2);
+    ///
+    /// assert_eq!(vec.len(), 1000);
+    /// assert!(vec.is_full());
+    /// assert!(vec.iter().enumerate().all(|(i, x)| *x == i * 2));
+    /// ```
+    pub fn par_from_fn<F>(fixed_capacity: usize, num_threads: usize, f: F) -> Self
+    where
+        F: Fn(usize) -> T + Sync,
+    {
+        let num_threads = num_threads.clamp(1, fixed_capacity.max(1));
+        if num_threads == 1 {
+            return Self::from_fn(fixed_capacity, f);
+        }
+
+        let chunk_size = fixed_capacity.div_ceil(num_threads);
+        let chunks = (0..fixed_capacity)
+            .step_by(chunk_size)
+            .map(|begin| begin..(begin + chunk_size).min(fixed_capacity));
+
+        let con_vec = ConcurrentFixedVec::from(Self::new(fixed_capacity));
+        let writer = ChunkWriter(&con_vec);
+
+        let results: Vec<_> = thread::scope(|s| {
+            let handles: Vec<_> = chunks
+                .map(|chunk| {
+                    let (writer, f) = (&writer, &f);
+                    (chunk.clone(), s.spawn(move || writer.write(chunk, f)))
+                })
+                .collect();
+            handles
+                .into_iter()
+                .map(|(chunk, handle)| (chunk, handle.join()))
+                .collect()
+        });
+
+        let (completed, failed): (Vec<_>, Vec<_>) =
+            results.into_iter().partition(|(_, result)| result.is_ok());
+
+        match failed.into_iter().next() {
+            Some((_, Err(payload))) => {
+                // elements of the failed chunks are dropped by their writers
+                for (chunk, _) in completed {
+                    // SAFETY: positions of the completed chunk are initialized and dropped only here
+                    unsafe { drop_chunk(&con_vec, chunk) };
+                }
+                drop(con_vec);
+                std::panic::resume_unwind(payload)
+            }
+            // SAFETY: every chunk is completely written, hence all positions are initialized
+            _ => unsafe { con_vec.into_inner(fixed_capacity) },
+        }
+    }
+}
+
+/// Writes disjoint chunks of a concurrent vector from multiple threads.
+struct ChunkWriter<'a, T>(&'a ConcurrentFixedVec<T>);
+
+// SAFETY: the writer only moves values of `T` into distinct positions of the vector, each written by one thread;
+// it never creates references to the elements. Hence, `T: Send` is sufficient.
+unsafe impl<T: Send> Sync for ChunkWriter<'_, T> {}
+
+impl<T> ChunkWriter<'_, T> {
+    fn write<F>(&self, chunk: Range<usize>, f: &F)
+    where
+        F: Fn(usize) -> T,
+    {
+        // the guard drops the elements written so far if `f` panics
+        let mut guard = WrittenGuard {
+            vec: self.0,
+            written: chunk.start..chunk.start,
+        };
+        for i in chunk {
+            // SAFETY: i < capacity, and each position is written by exactly one thread
+            unsafe { self.0.write(i, f(i)) };
+            guard.written.end += 1;
+        }
+        core::mem::forget(guard);
+    }
+}
+
+/// Drops the elements within `written` unless forgotten.
+struct WrittenGuard<'a, T> {
+    vec: &'a ConcurrentFixedVec<T>,
+    written: Range<usize>,
+}
+
+impl<T> Drop for WrittenGuard<'_, T> {
+    fn drop(&mut self) {
+        // SAFETY: positions within `written` are initialized by the thread owning this guard
+        unsafe { drop_chunk(self.vec, self.written.clone()) };
+    }
+}
+
+/// Drops the elements within the `chunk` of the concurrent vector in place.
+///
+/// # Safety
+///
+/// Positions within `chunk` must be initialized, and must not be accessed afterwards.
+unsafe fn drop_chunk<T>(vec: &ConcurrentFixedVec<T>, chunk: Range<usize>) {
+    if let Some(slice) = unsafe { vec.slices_mut(chunk) } {
+        unsafe { core::ptr::drop_in_place(slice) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::helpers::drop_counter::DropCounter;
+    use crate::prelude::*;
+    use alloc::string::{String, ToString};
+    use alloc::vec::Vec;
+    use core::cell::Cell;
+
+    #[test]
+    fn par_from_fn() {
+        for n in [0, 1, 2, 7, 64, 1000] {
+            for num_threads in [0, 1, 2, 3, 8, 2000] {
+                let vec = FixedVec::par_from_fn(n, num_threads, |i| i.to_string());
+                assert_eq!(vec.len(), n);
+                assert_eq!(vec.capacity(), n);
+                let expected: Vec<_> = (0..n).map(|i| i.to_string()).collect();
+                assert_eq!(vec, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn par_from_fn_zero_sized() {
+        let vec = FixedVec::par_from_fn(100, 4, |_| ());
+        assert_eq!(vec.len(), 100);
+        assert_eq!(vec.capacity(), 100);
+    }
+
+    #[test]
+    fn par_from_fn_send_but_not_sync() {
+        let vec = FixedVec::par_from_fn(50, 4, Cell::new);
+        assert!(vec.iter().enumerate().all(|(i, x)| x.get() == i));
+    }
+
+    #[test]
+    fn par_from_fn_drops_elements() {
+        let drops = DropCounter::new();
+        let vec = FixedVec::par_from_fn(40, 4, |_| drops.track());
+        assert_eq!(drops.dropped(), 0);
+
+        drop(vec);
+        assert_eq!(drops.dropped(), 40);
+    }
+
+    #[test]
+    fn par_from_fn_panic_drops_created_elements() {
+        for panic_at in [0, 5, 13, 39] {
+            let drops = DropCounter::new();
+
+            let result = std::panic::catch_unwind(|| {
+                FixedVec::par_from_fn(40, 4, |i| {
+                    assert_ne!(i, panic_at, "failed");
+                    drops.track()
+                })
+            });
+
+            let payload = result.err().expect("must propagate panic");
+            let message = payload.downcast_ref::<String>().expect("is-formatted");
+            assert!(message.contains("failed"));
+
+            assert!(drops.created() < 40);
+            drops.assert_no_leak();
+        }
+    }
+}
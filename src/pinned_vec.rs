@@ -1,5 +1,6 @@
 use crate::FixedVec;
 use crate::helpers::range::{range_end, range_start};
+use allocator_api2::alloc::Allocator;
 use core::cmp::Ordering;
 use core::iter::Rev;
 use core::ops::RangeBounds;
@@ -8,15 +9,13 @@ use orx_pinned_vec::utils::slice;
 use orx_pinned_vec::{CapacityState, PinnedVec};
 use orx_pseudo_default::PseudoDefault;
 
-impl<T> PseudoDefault for FixedVec<T> {
+impl<T, A: Allocator + Default> PseudoDefault for FixedVec<T, A> {
     fn pseudo_default() -> Self {
-        Self {
-            data: Default::default(),
-        }
+        Self::default()
     }
 }
 
-impl<T> PinnedVec<T> for FixedVec<T> {
+impl<T, A: Allocator + Default> PinnedVec<T> for FixedVec<T, A> {
     type IterRev<'a>
         = Rev<core::slice::Iter<'a, T>>
     where
@@ -94,7 +93,7 @@ impl<T> PinnedVec<T> for FixedVec<T> {
 
     fn push_get_ptr(&mut self, value: T) -> *const T {
         let idx = self.data.len();
-        self.data.push(value);
+        self.push_or_panic(value);
         unsafe { self.data.as_ptr().add(idx) }
     }
 
@@ -180,12 +179,17 @@ impl<T> PinnedVec<T> for FixedVec<T> {
         slice::contains_ptr(self.data.as_slice(), element_ptr)
     }
 
+    /// Clears the vector, removing all values.
+    ///
+    /// Note that all [`FixedVecIdx`](crate::FixedVecIdx) handles created before clearing the vector
+    /// are invalidated.
     fn clear(&mut self) {
+        self.invalidate_indices();
         self.data.clear();
     }
 
     fn capacity(&self) -> usize {
-        self.data.capacity()
+        self.capacity
     }
 
     fn capacity_state(&self) -> CapacityState {
@@ -210,6 +214,29 @@ impl<T> PinnedVec<T> for FixedVec<T> {
         self.data.extend_from_slice(other);
     }
 
+    /// Extends this vector by copying `count` * `size_of::<T>()` bytes from `src` to the end of the vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is not enough room in the vector for `count` elements;
+    /// i.e., `self.room() < count`.
+    ///
+    /// # Safety
+    ///
+    /// Behavior is undefined if any of the following conditions are violated:
+    ///
+    /// * `src` must be valid for reads of `count * size_of::<T>()` bytes.
+    /// * `src` must be properly aligned.
+    /// * The region of memory beginning at `src` with a size of `count * size_of::<T>()`
+    ///   bytes must *not* overlap with the region of memory of this vector.
+    unsafe fn extend_from_nonoverlapping(&mut self, src: *const T, count: usize) {
+        self.panic_if_not_enough_room_for(count);
+        let len = self.data.len();
+        let dst = unsafe { self.data.as_mut_ptr().add(len) };
+        unsafe { dst.copy_from_nonoverlapping(src, count) };
+        unsafe { self.data.set_len(len + count) };
+    }
+
     #[inline(always)]
     fn get(&self, index: usize) -> Option<&T> {
         self.data.get(index)
@@ -281,12 +308,19 @@ impl<T> PinnedVec<T> for FixedVec<T> {
     #[inline(always)]
     fn insert(&mut self, index: usize, element: T) {
         self.panic_if_not_enough_room_for(1);
+        if index < self.data.len() {
+            self.invalidate_indices();
+        }
         self.data.insert(index, element)
     }
 
     #[inline(always)]
     fn remove(&mut self, index: usize) -> T {
-        self.data.remove(index)
+        let removed = self.data.remove(index);
+        if index < self.data.len() {
+            self.invalidate_indices();
+        }
+        removed
     }
 
     #[inline(always)]
@@ -301,6 +335,9 @@ impl<T> PinnedVec<T> for FixedVec<T> {
 
     #[inline(always)]
     fn truncate(&mut self, len: usize) {
+        if len < self.data.len() {
+            self.invalidate_indices();
+        }
         self.data.truncate(len)
     }
 
@@ -434,16 +471,19 @@ impl<T> PinnedVec<T> for FixedVec<T> {
 
     #[inline(always)]
     fn get_ptr(&self, index: usize) -> Option<*const T> {
-        (index < self.data.capacity()).then(|| unsafe { self.data.as_ptr().add(index) })
+        (index < self.capacity).then(|| unsafe { self.data.as_ptr().add(index) })
     }
 
     #[inline(always)]
     fn get_ptr_mut(&mut self, index: usize) -> Option<*mut T> {
-        (index < self.data.capacity()).then(|| unsafe { self.data.as_mut_ptr().add(index) })
+        (index < self.capacity).then(|| unsafe { self.data.as_mut_ptr().add(index) })
     }
 
     #[inline(always)]
     unsafe fn set_len(&mut self, new_len: usize) {
+        if new_len < self.data.len() {
+            self.invalidate_indices();
+        }
         unsafe { self.data.set_len(new_len) }
     }
 
@@ -458,6 +498,9 @@ impl<T> PinnedVec<T> for FixedVec<T> {
     where
         T: Ord,
     {
+        if self.data.len() > 1 {
+            self.invalidate_indices();
+        }
         self.data.sort();
     }
 
@@ -465,6 +508,9 @@ impl<T> PinnedVec<T> for FixedVec<T> {
     where
         F: FnMut(&T, &T) -> Ordering,
     {
+        if self.data.len() > 1 {
+            self.invalidate_indices();
+        }
         self.data.sort_by(compare)
     }
 
@@ -473,6 +519,9 @@ impl<T> PinnedVec<T> for FixedVec<T> {
         F: FnMut(&T) -> K,
         K: Ord,
     {
+        if self.data.len() > 1 {
+            self.invalidate_indices();
+        }
         self.data.sort_by_key(f)
     }
 
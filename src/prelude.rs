@@ -1,4 +1,8 @@
-pub use crate::FixedVec;
+#[cfg(feature = "mmap")]
+pub use crate::MmapFixedVec;
+pub use crate::{
+    FixedArrayVec, FixedRing, FixedSlab, FixedVec, FixedVecBuilder, FixedVecFullError, FixedVecIdx,
+};
 pub use orx_iterable::{Collection, CollectionMut, Iterable};
 pub use orx_pinned_vec::{
     ConcurrentPinnedVec, IntoConcurrentPinnedVec, PinnedVec, PinnedVecGrowthError,
@@ -0,0 +1,373 @@
+use crate::{FixedVec, FixedVecFullError};
+use allocator_api2::alloc::{Allocator, Global};
+use core::fmt::Debug;
+use core::mem::MaybeUninit;
+
+/// Writes elements directly into the spare capacity of a [`FixedVec`]; created by [`FixedVec::write_with`].
+///
+/// Each written element is placed at the next uninitialized position after the elements of the vector.
+/// The written elements become part of the vector when the writer is dropped, which also happens
+/// when the closure passed to `write_with` panics; hence, no element is leaked or dropped twice.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_fixed_vec::prelude::*;
+///
+/// let mut vec = FixedVec::new(4);
+/// vec.push(0);
+///
+/// let num_written = vec.write_with(|writer| {
+///     while writer.try_write(writer.written() + 1).is_ok() {}
+///     writer.written()
+/// });
+///
+/// assert_eq!(num_written, 3);
+/// assert_eq!(vec, &[0, 1, 2, 3]);
+/// ```
+pub struct SpareCapacityWriter<'a, T, A: Allocator = Global> {
+    vec: &'a mut FixedVec<T, A>,
+    written: usize,
+}
+
+impl<T, A: Allocator> SpareCapacityWriter<'_, T, A> {
+    /// Number of elements written so far by this writer.
+    pub fn written(&self) -> usize {
+        self.written
+    }
+
+    /// Number of elements that can still be written; i.e., remaining spare capacity of the vector.
+    pub fn room(&self) -> usize {
+        self.vec.room() - self.written
+    }
+
+    /// Returns whether or not there is room to write another element.
+    pub fn is_full(&self) -> bool {
+        self.room() == 0
+    }
+
+    /// Writes the `value` to the next uninitialized position of the vector if there is available room;
+    /// returns back the `value` in a [`FixedVecFullError`] otherwise.
+    pub fn try_write(&mut self, value: T) -> Result<(), FixedVecFullError<T>> {
+        match self.is_full() {
+            true => {
+                let len = self.vec.data.len() + self.written;
+                Err(FixedVecFullError::new(value, self.vec.capacity, len))
+            }
+            false => {
+                self.vec.data.spare_capacity_mut()[self.written].write(value);
+                self.written += 1;
+                Ok(())
+            }
+        }
+    }
+
+    /// Writes the `value` to the next uninitialized position of the vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no room left; i.e., if `self.is_full()`.
+    pub fn write(&mut self, value: T) {
+        assert!(!self.is_full(), "{}", ERR_MSG_OUT_OF_ROOM);
+        self.vec.data.spare_capacity_mut()[self.written].write(value);
+        self.written += 1;
+    }
+}
+
+impl<T, A: Allocator> Drop for SpareCapacityWriter<'_, T, A> {
+    fn drop(&mut self) {
+        // SAFETY: exactly the first `written` positions of the spare capacity are initialized
+        unsafe { self.vec.commit(self.written) };
+    }
+}
+
+impl<T, A: Allocator> Debug for SpareCapacityWriter<'_, T, A> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SpareCapacityWriter")
+            .field("written", &self.written)
+            .field("room", &self.room())
+            .finish()
+    }
+}
+
+impl<T, A: Allocator> FixedVec<T, A> {
+    /// Writes the `value` to the first position of the spare capacity and returns a mutable reference to the
+    /// pushed element if there is available room; returns back the `value` otherwise.
+    ///
+    /// Unlike [`try_push`](Self::try_push), the rejected value is returned as is rather than in a
+    /// [`FixedVecFullError`], mirroring `Vec::push_within_capacity` of the standard library.
+    /// Since the vector never reallocates, the returned reference points to the pinned location of the element.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_fixed_vec::prelude::*;
+    ///
+    /// let mut vec = FixedVec::new(2);
+    ///
+    /// let first = vec.push_within_capacity('a').unwrap();
+    /// *first = 'b';
+    /// assert_eq!(vec.push_within_capacity('c'), Ok(&mut 'c'));
+    /// assert_eq!(vec.push_within_capacity('d'), Err('d'));
+    ///
+    /// assert_eq!(vec, &['b', 'c']);
+    /// ```
+    pub fn push_within_capacity(&mut self, value: T) -> Result<&mut T, T> {
+        match self.spare_capacity_mut().first_mut() {
+            Some(slot) => {
+                slot.write(value);
+                // SAFETY: the first position of the spare capacity is just initialized
+                unsafe { self.commit(1) };
+                let last = self.data.len() - 1;
+                Ok(&mut self.data[last])
+            }
+            None => Err(value),
+        }
+    }
+
+    /// Returns the remaining spare capacity of the vector as a slice of `MaybeUninit<T>`;
+    /// i.e., the `room()` positions following the elements of the vector.
+    ///
+    /// The returned slice can be used to fill the vector with data, for instance by a decoder or
+    /// by a foreign function, before marking the data as initialized using [`commit`](Self::commit).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_fixed_vec::prelude::*;
+    ///
+    /// let mut vec = FixedVec::new(5);
+    /// vec.push(0);
+    ///
+    /// let spare = vec.spare_capacity_mut();
+    /// assert_eq!(spare.len(), 4);
+    /// spare[0].write(1);
+    /// spare[1].write(2);
+    ///
+    /// unsafe { vec.commit(2) };
+    /// assert_eq!(vec, &[0, 1, 2]);
+    /// ```
+    pub fn spare_capacity_mut(&mut self) -> &mut [MaybeUninit<T>] {
+        let room = self.room();
+        &mut self.data.spare_capacity_mut()[..room]
+    }
+
+    /// Marks the first `num_written` positions of the spare capacity as initialized,
+    /// increasing the length of the vector by `num_written`.
+    ///
+    /// Memory locations of the existing elements do not change.
+    ///
+    /// # Safety
+    ///
+    /// The first `num_written` elements of [`spare_capacity_mut`](Self::spare_capacity_mut) must be initialized.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_written` exceeds the available room; i.e., if `num_written > self.room()`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_fixed_vec::prelude::*;
+    ///
+    /// let mut vec = FixedVec::<u8>::new(8);
+    ///
+    /// // fills the buffer and returns the number of bytes written, as a C callback would
+    /// fn decode(buffer: &mut [core::mem::MaybeUninit<u8>]) -> usize {
+    ///     for (i, x) in buffer.iter_mut().take(3).enumerate() {
+    ///         x.write(i as u8 * 2);
+    ///     }
+    ///     3
+    /// }
+    ///
+    /// let num_written = decode(vec.spare_capacity_mut());
+    /// unsafe { vec.commit(num_written) };
+    /// assert_eq!(vec, &[0, 2, 4]);
+    /// ```
+    pub unsafe fn commit(&mut self, num_written: usize) {
+        self.panic_if_not_enough_room_for(num_written);
+        let len = self.data.len() + num_written;
+        unsafe { self.data.set_len(len) };
+    }
+
+    /// Calls `write` with a [`SpareCapacityWriter`] which writes elements directly into the spare capacity
+    /// of the vector, and returns the result of the call.
+    ///
+    /// Elements written by the writer are committed to the vector when `write` returns, or when it panics.
+    /// Memory locations of the existing elements do not change.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_fixed_vec::prelude::*;
+    ///
+    /// let mut vec = FixedVec::new(6);
+    /// vec.push('a');
+    ///
+    /// vec.write_with(|writer| {
+    ///     for c in "bcd".chars() {
+    ///         writer.write(c);
+    ///     }
+    ///     assert_eq!(writer.room(), 2);
+    /// });
+    ///
+    /// assert_eq!(vec, &['a', 'b', 'c', 'd']);
+    /// ```
+    pub fn write_with<F, R>(&mut self, write: F) -> R
+    where
+        F: FnOnce(&mut SpareCapacityWriter<'_, T, A>) -> R,
+    {
+        let mut writer = SpareCapacityWriter {
+            vec: self,
+            written: 0,
+        };
+        write(&mut writer)
+    }
+}
+
+const ERR_MSG_OUT_OF_ROOM: &str =
+    "SpareCapacityWriter has written all positions of the spare capacity of the FixedVec.";
+
+#[cfg(test)]
+mod tests {
+    use crate::helpers::drop_counter::DropCounter;
+    use crate::prelude::*;
+    use alloc::string::{String, ToString};
+    use alloc::vec::Vec;
+
+    #[test]
+    fn spare_capacity_mut() {
+        let mut vec = FixedVec::new(4);
+        assert_eq!(vec.spare_capacity_mut().len(), 4);
+
+        vec.push(0);
+        let addr0 = &vec[0] as *const i32;
+        assert_eq!(vec.spare_capacity_mut().len(), 3);
+
+        for (i, x) in vec.spare_capacity_mut().iter_mut().enumerate() {
+            x.write(i as i32 + 1);
+        }
+        unsafe { vec.commit(3) };
+
+        assert_eq!(vec, &[0, 1, 2, 3]);
+        assert!(vec.spare_capacity_mut().is_empty());
+        assert_eq!(addr0, &vec[0] as *const i32);
+    }
+
+    #[test]
+    fn commit_zero() {
+        let mut vec = FixedVec::<String>::new(2);
+        unsafe { vec.commit(0) };
+        assert!(vec.is_empty());
+
+        vec.push("a".to_string());
+        vec.push("b".to_string());
+        unsafe { vec.commit(0) };
+        assert_eq!(vec, ["a", "b"].map(String::from));
+    }
+
+    #[test]
+    #[should_panic]
+    fn commit_more_than_room() {
+        let mut vec = FixedVec::<u32>::new(3);
+        vec.push(0);
+        for x in vec.spare_capacity_mut() {
+            x.write(1);
+        }
+        unsafe { vec.commit(3) };
+    }
+
+    #[test]
+    fn write_with() {
+        let mut vec = FixedVec::new(5);
+        vec.push(0.to_string());
+
+        let room = vec.write_with(|writer| {
+            assert_eq!(writer.written(), 0);
+            assert_eq!(writer.room(), 4);
+
+            writer.write(1.to_string());
+            assert!(writer.try_write(2.to_string()).is_ok());
+            assert_eq!(writer.written(), 2);
+            writer.room()
+        });
+
+        assert_eq!(room, 2);
+        assert_eq!(vec, (0..3).map(|x| x.to_string()).collect::<Vec<_>>());
+        assert_eq!(vec.room(), 2);
+    }
+
+    #[test]
+    fn write_with_until_full() {
+        let mut vec = FixedVec::new(3);
+
+        vec.write_with(|writer| {
+            let mut i = 0;
+            while !writer.is_full() {
+                writer.write(i);
+                i += 1;
+            }
+
+            let error = writer.try_write(42).expect_err("is-full");
+            assert_eq!(error.capacity(), 3);
+            assert_eq!(error.len(), 3);
+            assert_eq!(error.into_rejected(), 42);
+        });
+
+        assert_eq!(vec, &[0, 1, 2]);
+        vec.write_with(|writer| assert!(writer.is_full()));
+        assert_eq!(vec, &[0, 1, 2]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn write_when_full() {
+        let mut vec = FixedVec::new(1);
+        vec.write_with(|writer| {
+            writer.write(0);
+            writer.write(1);
+        });
+    }
+
+    #[test]
+    fn push_within_capacity() {
+        let mut vec = FixedVec::new(3);
+        vec.push(0.to_string());
+
+        let pushed = vec.push_within_capacity(1.to_string()).expect("has-room");
+        pushed.push('!');
+        let addr = pushed as *const String;
+
+        assert!(vec.push_within_capacity(2.to_string()).is_ok());
+        assert_eq!(vec.push_within_capacity(3.to_string()), Err(3.to_string()));
+
+        assert_eq!(vec, ["0", "1!", "2"].map(String::from));
+        assert_eq!(addr, &vec[1] as *const String);
+
+        let mut vec = FixedVec::<()>::new(1);
+        assert_eq!(vec.push_within_capacity(()), Ok(&mut ()));
+        assert_eq!(vec.push_within_capacity(()), Err(()));
+    }
+
+    #[test]
+    fn write_with_panic_keeps_written_elements() {
+        let drops = DropCounter::new();
+        let mut vec = FixedVec::new(4);
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            vec.write_with(|writer| {
+                writer.write(drops.track());
+                writer.write(drops.track());
+                let _ = writer.written().checked_sub(3).expect("fails");
+            })
+        }));
+
+        assert!(result.is_err());
+        assert_eq!(vec.len(), 2);
+        assert_eq!(drops.created(), 2);
+        assert_eq!(drops.dropped(), 0);
+
+        drop(vec);
+        drops.assert_no_leak();
+    }
+}
@@ -0,0 +1,115 @@
+#![cfg_attr(feature = "nightly", feature(allocator_api))]
+
+use allocator_api2::alloc::{AllocError, Allocator, Global, Layout};
+use core::ptr::NonNull;
+use orx_fixed_vec::*;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Allocator delegating to the global allocator while counting the live allocations.
+#[derive(Clone, Default)]
+struct CountingAllocator {
+    live: Arc<AtomicUsize>,
+}
+
+impl CountingAllocator {
+    fn live(&self) -> usize {
+        self.live.load(Ordering::SeqCst)
+    }
+}
+
+unsafe impl Allocator for CountingAllocator {
+    fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        let ptr = Global.allocate(layout)?;
+        self.live.fetch_add(1, Ordering::SeqCst);
+        Ok(ptr)
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        self.live.fetch_sub(1, Ordering::SeqCst);
+        unsafe { Global.deallocate(ptr, layout) }
+    }
+}
+
+#[test]
+fn new_in() {
+    let alloc = CountingAllocator::default();
+
+    let mut vec = FixedVec::new_in(8, alloc.clone());
+    assert_eq!(alloc.live(), 1);
+    assert_eq!(vec.capacity(), 8);
+
+    for i in 0..8 {
+        vec.push(i.to_string());
+    }
+    assert!(vec.try_push(8.to_string()).is_err());
+    assert_eq!(alloc.live(), 1);
+
+    let clone = vec.clone();
+    assert_eq!(alloc.live(), 2);
+    assert_eq!(clone, vec);
+
+    drop(vec);
+    drop(clone);
+    assert_eq!(alloc.live(), 0);
+}
+
+#[test]
+fn pinned_vec_tests() {
+    let alloc = CountingAllocator::default();
+
+    for cap in [0, 10, 124] {
+        orx_pinned_vec::test_pinned_vec(FixedVec::new_in(cap, alloc.clone()), cap);
+        assert_eq!(alloc.live(), 0);
+    }
+}
+
+#[test]
+fn into_concurrent_and_back() {
+    let alloc = CountingAllocator::default();
+
+    let mut vec = FixedVec::new_in(6, alloc.clone());
+    vec.push("a".to_string());
+
+    let con_vec = vec.into_concurrent_filled_with(|| "x".to_string());
+    unsafe { con_vec.get_ptr_mut(1).write("b".to_string()) };
+    assert_eq!(alloc.live(), 1);
+
+    let clone = unsafe { con_vec.clone_with_len(2) };
+    assert_eq!(alloc.live(), 2);
+
+    let vec: FixedVec<String, CountingAllocator> = unsafe { con_vec.into_inner(6) };
+    assert_eq!(vec, ["a", "b", "x", "x", "x", "x"].map(String::from));
+    assert_eq!(vec.allocator().live(), 2);
+
+    let iter = unsafe { clone.into_iter(0..2) };
+    assert_eq!(iter.collect::<Vec<_>>(), ["a", "b"]);
+    assert_eq!(alloc.live(), 1);
+
+    let inner = vec.into_allocator_vec();
+    assert_eq!(inner.capacity(), 6);
+    drop(inner);
+    assert_eq!(alloc.live(), 0);
+}
+
+#[test]
+fn borrowed_allocator_without_default() {
+    let alloc = CountingAllocator::default();
+
+    let mut vec = FixedVec::new_in(4, &alloc);
+    vec.try_push(0).expect("has room");
+    let bag = ConcurrentFixedBag::from(vec);
+    std::thread::scope(|s| {
+        for i in 1..4 {
+            let bag = &bag;
+            s.spawn(move || bag.push(i).expect("has room"));
+        }
+    });
+    assert_eq!(alloc.live(), 1);
+
+    let vec: FixedVec<i32, &CountingAllocator> = bag.into_inner();
+    let mut sorted: Vec<_> = vec.into_iter().collect();
+    sorted.sort();
+    assert_eq!(sorted, [0, 1, 2, 3]);
+    assert_eq!(alloc.live(), 0);
+}
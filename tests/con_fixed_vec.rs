@@ -45,9 +45,9 @@ fn into_concurrent_fill_with() {
 fn reserve_fill_with() {
     let vec = FixedVec::<String>::new(42);
 
-    let mut con_vec = vec.into_concurrent_filled_with(|| "x".to_string());
+    let mut con_vec = vec.into_concurrent();
 
-    unsafe { con_vec.reserve_maximum_concurrent_capacity_fill_with(42, 74, || "y".to_string()) };
+    unsafe { con_vec.reserve_maximum_concurrent_capacity_fill_with(0, 74, || "y".to_string()) };
     let new_capacity = con_vec.capacity();
     assert!(new_capacity >= 74);
     assert_eq!(con_vec.max_capacity(), new_capacity);
@@ -55,13 +55,29 @@ fn reserve_fill_with() {
     let vec = unsafe { con_vec.into_inner(new_capacity) };
 
     assert_eq!(
-        vec[0..42],
-        (0..42).map(|_| "x".to_string()).collect::<Vec<_>>()
-    );
-    assert_eq!(
-        vec[42..],
-        (42..new_capacity)
+        vec[..],
+        (0..new_capacity)
             .map(|_| "y".to_string())
             .collect::<Vec<_>>()
     );
 }
+
+#[test]
+fn reserve_fill_with_non_empty_keeps_elements_pinned() {
+    let vec = FixedVec::<String>::new(42);
+
+    let mut con_vec = vec.into_concurrent_filled_with(|| "x".to_string());
+    let first = unsafe { con_vec.get(0) }.map(|x| x as *const String);
+
+    let new_capacity = unsafe {
+        con_vec.reserve_maximum_concurrent_capacity_fill_with(42, 74, || "y".to_string())
+    };
+    assert_eq!(new_capacity, 42);
+    assert_eq!(con_vec.capacity(), 42);
+    assert_eq!(con_vec.max_capacity(), 42);
+    assert_eq!(unsafe { con_vec.get(0) }.map(|x| x as *const String), first);
+
+    let vec = unsafe { con_vec.into_inner(42) };
+
+    assert_eq!(vec, (0..42).map(|_| "x".to_string()).collect::<Vec<_>>());
+}
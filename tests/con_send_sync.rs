@@ -0,0 +1,104 @@
+use orx_fixed_vec::*;
+use std::cell::Cell;
+use std::rc::Rc;
+use std::sync::MutexGuard;
+
+fn assert_send<T: Send>() {}
+
+fn assert_sync<T: Sync>() {}
+
+/// Fails to compile if `$x` implements `$trait`, due to ambiguity of the two implementations.
+macro_rules! assert_not_impl {
+    ($x:ty, $trait:path) => {
+        const _: fn() = || {
+            trait AmbiguousIfImpl<A> {
+                fn some_item() {}
+            }
+            impl<T: ?Sized> AmbiguousIfImpl<()> for T {}
+            struct Invalid;
+            impl<T: ?Sized + $trait> AmbiguousIfImpl<Invalid> for T {}
+            let _ = <$x as AmbiguousIfImpl<_>>::some_item;
+        };
+    };
+}
+
+// T: Send + Sync
+
+#[test]
+fn send_sync() {
+    assert_send::<ConcurrentFixedVec<String>>();
+    assert_sync::<ConcurrentFixedVec<String>>();
+
+    assert_send::<ConcurrentFixedArrayVec<String, 4>>();
+    assert_sync::<ConcurrentFixedArrayVec<String, 4>>();
+
+    assert_send::<<ConcurrentFixedVec<String> as ConcurrentPinnedVec<String>>::IntoIter>();
+    assert_sync::<<ConcurrentFixedVec<String> as ConcurrentPinnedVec<String>>::IntoIter>();
+
+    assert_send::<ConcurrentFixedBag<String>>();
+    assert_sync::<ConcurrentFixedBag<String>>();
+}
+
+// T: Send + !Sync
+
+assert_not_impl!(ConcurrentFixedVec<Cell<u32>>, Sync);
+assert_not_impl!(ConcurrentFixedArrayVec<Cell<u32>, 4>, Sync);
+
+#[test]
+fn send_not_sync() {
+    assert_send::<ConcurrentFixedVec<Cell<u32>>>();
+    assert_send::<ConcurrentFixedArrayVec<Cell<u32>, 4>>();
+    assert_send::<<ConcurrentFixedVec<Cell<u32>> as ConcurrentPinnedVec<Cell<u32>>>::IntoIter>();
+
+    // only writes are performed through a shared bag
+    assert_sync::<ConcurrentFixedBag<Cell<u32>>>();
+}
+
+// !Send + Sync
+
+assert_not_impl!(ConcurrentFixedVec<MutexGuard<'static, u32>>, Send);
+assert_not_impl!(ConcurrentFixedVec<MutexGuard<'static, u32>>, Sync);
+assert_not_impl!(ConcurrentFixedArrayVec<MutexGuard<'static, u32>, 4>, Send);
+assert_not_impl!(ConcurrentFixedArrayVec<MutexGuard<'static, u32>, 4>, Sync);
+
+// !Send + !Sync
+
+assert_not_impl!(ConcurrentFixedVec<Rc<u32>>, Send);
+assert_not_impl!(ConcurrentFixedVec<Rc<u32>>, Sync);
+assert_not_impl!(ConcurrentFixedArrayVec<Rc<u32>, 4>, Send);
+assert_not_impl!(ConcurrentFixedArrayVec<Rc<u32>, 4>, Sync);
+assert_not_impl!(ConcurrentFixedBag<Rc<u32>>, Send);
+assert_not_impl!(ConcurrentFixedBag<Rc<u32>>, Sync);
+
+// usage
+
+#[test]
+fn share_across_threads() {
+    let num_threads = 4;
+    let chunk = 16;
+    let capacity = num_threads * chunk;
+
+    let con_vec = FixedVec::<String>::new(capacity).into_concurrent();
+
+    std::thread::scope(|s| {
+        for t in 0..num_threads {
+            let con_vec = &con_vec;
+            s.spawn(move || {
+                // each thread writes to a disjoint range of positions
+                for i in t * chunk..(t + 1) * chunk {
+                    unsafe { con_vec.get_ptr_mut(i).write(i.to_string()) };
+                }
+            });
+        }
+    });
+
+    let con_vec = std::thread::spawn(move || con_vec)
+        .join()
+        .expect("no-panic");
+
+    let vec = unsafe { con_vec.into_inner(capacity) };
+    assert_eq!(
+        vec,
+        (0..capacity).map(|i| i.to_string()).collect::<Vec<_>>()
+    );
+}
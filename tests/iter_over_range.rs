@@ -1,8 +1,9 @@
 use orx_fixed_vec::*;
 
 #[test]
+#[allow(clippy::reversed_empty_ranges)]
 fn iter_over_range() {
-    let vec = FixedVec::from_iter([0, 1, 2, 3, 4, 5, 6].into_iter());
+    let vec = FixedVec::from_iter([0, 1, 2, 3, 4, 5, 6]);
     let con_vec = vec.into_concurrent();
 
     unsafe {
@@ -0,0 +1,285 @@
+//! Model checked concurrency tests of `ConcurrentFixedVec` and its into iterator.
+//!
+//! Run with:
+//!
+//! ```bash
+//! RUSTFLAGS="--cfg loom" cargo test --release --test loom
+//! ```
+//!
+//! Note that loom does not track the memory of the vector, which is written through raw pointers.
+//! Therefore, elements access loom-tracked cells, either as their own content such as `Slot`, or as the
+//! shadow of their position such as `Tracked`, so that unsynchronized accesses are reported as data races.
+#![cfg(loom)]
+
+use loom::cell::UnsafeCell;
+use loom::sync::Arc;
+use loom::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use loom::thread;
+use orx_fixed_vec::*;
+
+/// Element with interior mutability tracked by loom, so that unsynchronized accesses to the
+/// same position of the vector are reported as data races.
+#[derive(Default)]
+struct Slot(UnsafeCell<Option<usize>>);
+
+// SAFETY: accesses are synchronized by the tests; loom verifies this.
+unsafe impl Sync for Slot {}
+
+impl Slot {
+    fn write(&self, value: usize) {
+        self.0.with_mut(|p| unsafe { *p = Some(value) });
+    }
+
+    fn read(&self) -> Option<usize> {
+        self.0.with(|p| unsafe { *p })
+    }
+}
+
+/// Element counting the number of times it is dropped.
+///
+/// The element writes the shadow slot of its position when it is created and reads it when it is dropped;
+/// hence, creating or dropping elements at the same position without synchronization is reported by loom.
+struct Tracked {
+    shadow: Arc<Vec<Slot>>,
+    position: usize,
+    drops: Arc<AtomicUsize>,
+}
+
+impl Tracked {
+    fn new(shadow: &Arc<Vec<Slot>>, position: usize, drops: &Arc<AtomicUsize>) -> Self {
+        shadow[position].write(position);
+        Self {
+            shadow: shadow.clone(),
+            position,
+            drops: drops.clone(),
+        }
+    }
+}
+
+impl Drop for Tracked {
+    fn drop(&mut self) {
+        assert_eq!(self.shadow[self.position].read(), Some(self.position));
+        self.drops.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+fn shadow_slots(len: usize) -> Arc<Vec<Slot>> {
+    Arc::new((0..len).map(|_| Slot::default()).collect())
+}
+
+/// Fills the `range` of the concurrent vector with tracked elements.
+fn fill_tracked(
+    con_vec: &ConcurrentFixedVec<Tracked>,
+    range: core::ops::Range<usize>,
+    shadow: &Arc<Vec<Slot>>,
+    drops: &Arc<AtomicUsize>,
+) {
+    let next = core::cell::Cell::new(range.start);
+    con_vec.fill_with(range, || {
+        let position = next.replace(next.get() + 1);
+        Tracked::new(shadow, position, drops)
+    });
+}
+
+fn unwrap_arc<T>(arc: Arc<T>) -> T {
+    match Arc::try_unwrap(arc) {
+        Ok(x) => x,
+        Err(_) => panic!("all threads holding a reference are joined"),
+    }
+}
+
+#[test]
+fn writers_and_reader_over_slices() {
+    loom::model(|| {
+        let con_vec = Arc::new(FixedVec::<Slot>::new(2).into_concurrent_filled_with(Slot::default));
+        let ready = Arc::new([AtomicBool::new(false), AtomicBool::new(false)]);
+
+        let writers: Vec<_> = (0..2)
+            .map(|t| {
+                let con_vec = con_vec.clone();
+                let ready = ready.clone();
+                thread::spawn(move || {
+                    let slot = unsafe { &*con_vec.get_ptr_mut(t) };
+                    slot.write(10 + t);
+                    ready[t].store(true, Ordering::Release);
+                })
+            })
+            .collect();
+
+        for i in 0..2 {
+            if ready[i].load(Ordering::Acquire) {
+                let slice = con_vec.slices(i..(i + 1)).expect("in-bounds");
+                assert_eq!(slice[0].read(), Some(10 + i));
+            }
+        }
+
+        for w in writers {
+            w.join().expect("no-panic");
+        }
+
+        let vec = unsafe { unwrap_arc(con_vec).into_inner(2) };
+        assert_eq!(
+            vec.iter().map(Slot::read).collect::<Vec<_>>(),
+            [Some(10), Some(11)]
+        );
+    });
+}
+
+#[test]
+fn writer_and_reader_over_range() {
+    loom::model(|| {
+        let con_vec = Arc::new(FixedVec::<Slot>::new(2).into_concurrent_filled_with(Slot::default));
+        let num_written = Arc::new(AtomicUsize::new(0));
+
+        let writer = {
+            let con_vec = con_vec.clone();
+            let num_written = num_written.clone();
+            thread::spawn(move || {
+                for i in 0..2 {
+                    unsafe { &*con_vec.get_ptr_mut(i) }.write(i);
+                    num_written.fetch_add(1, Ordering::Release);
+                }
+            })
+        };
+
+        let reader = {
+            let con_vec = con_vec.clone();
+            let num_written = num_written.clone();
+            thread::spawn(move || {
+                let len = num_written.load(Ordering::Acquire);
+                let values: Vec<_> = unsafe { con_vec.iter_over_range(0..len) }
+                    .map(Slot::read)
+                    .collect();
+                assert_eq!(values, (0..len).map(Some).collect::<Vec<_>>());
+            })
+        };
+
+        writer.join().expect("no-panic");
+        reader.join().expect("no-panic");
+
+        let vec = unsafe { unwrap_arc(con_vec).into_inner(2) };
+        assert_eq!(
+            vec.iter().map(Slot::read).collect::<Vec<_>>(),
+            [Some(0), Some(1)]
+        );
+    });
+}
+
+#[test]
+fn fill_with_disjoint_ranges_drops_each_element_once() {
+    loom::model(|| {
+        let drops = Arc::new(AtomicUsize::new(0));
+        let shadow = shadow_slots(4);
+        let con_vec = Arc::new(FixedVec::<Tracked>::new(4).into_concurrent());
+
+        let fillers: Vec<_> = [0..2, 2..4]
+            .into_iter()
+            .map(|range| {
+                let (con_vec, shadow, drops) = (con_vec.clone(), shadow.clone(), drops.clone());
+                thread::spawn(move || fill_tracked(&con_vec, range, &shadow, &drops))
+            })
+            .collect();
+
+        for f in fillers {
+            f.join().expect("no-panic");
+        }
+        assert_eq!(drops.load(Ordering::SeqCst), 0);
+
+        let vec = unsafe { unwrap_arc(con_vec).into_inner(4) };
+        assert_eq!(vec.len(), 4);
+        assert_eq!(drops.load(Ordering::SeqCst), 0);
+
+        drop(vec);
+        assert_eq!(drops.load(Ordering::SeqCst), 4);
+    });
+}
+
+// reported by loom as concurrent writes to the shadow slot, or by the `debug-checks` feature as a double write
+#[test]
+#[should_panic]
+fn fill_with_overlapping_ranges_is_reported() {
+    loom::model(|| {
+        let drops = Arc::new(AtomicUsize::new(0));
+        let shadow = shadow_slots(3);
+        let con_vec = Arc::new(FixedVec::<Tracked>::new(3).into_concurrent());
+
+        let fillers: Vec<_> = [0..2, 1..3]
+            .into_iter()
+            .map(|range| {
+                let (con_vec, shadow, drops) = (con_vec.clone(), shadow.clone(), drops.clone());
+                thread::spawn(move || fill_tracked(&con_vec, range, &shadow, &drops))
+            })
+            .collect();
+
+        for f in fillers {
+            f.join().expect("no-panic");
+        }
+        drop(unwrap_arc(con_vec));
+    });
+}
+
+#[test]
+fn clear_drops_each_written_element_once() {
+    loom::model(|| {
+        let drops = Arc::new(AtomicUsize::new(0));
+        let shadow = shadow_slots(3);
+        let con_vec = Arc::new(FixedVec::<Tracked>::new(3).into_concurrent());
+
+        let writers: Vec<_> = (0..2)
+            .map(|i| {
+                let (con_vec, shadow, drops) = (con_vec.clone(), shadow.clone(), drops.clone());
+                thread::spawn(move || unsafe { con_vec.write(i, Tracked::new(&shadow, i, &drops)) })
+            })
+            .collect();
+
+        for w in writers {
+            w.join().expect("no-panic");
+        }
+
+        let mut con_vec = unwrap_arc(con_vec);
+        unsafe { con_vec.clear(2) };
+        assert_eq!(drops.load(Ordering::SeqCst), 2);
+
+        drop(con_vec);
+        assert_eq!(drops.load(Ordering::SeqCst), 2);
+    });
+}
+
+#[test]
+fn into_iter_consumed_across_threads_drops_each_element_once() {
+    loom::model(|| {
+        let drops = Arc::new(AtomicUsize::new(0));
+        let shadow = shadow_slots(4);
+        let con_vec = Arc::new(FixedVec::<Tracked>::new(4).into_concurrent());
+
+        let writers: Vec<_> = [0..2, 2..3]
+            .into_iter()
+            .map(|range| {
+                let (con_vec, shadow, drops) = (con_vec.clone(), shadow.clone(), drops.clone());
+                thread::spawn(move || {
+                    for i in range {
+                        unsafe { con_vec.write(i, Tracked::new(&shadow, i, &drops)) };
+                    }
+                })
+            })
+            .collect();
+
+        for w in writers {
+            w.join().expect("no-panic");
+        }
+
+        let mut iter = unsafe { unwrap_arc(con_vec).into_iter(0..3) };
+        let first = iter.next().expect("has-element");
+
+        let consumer = thread::spawn(move || {
+            let second = iter.next().expect("has-element");
+            drop(second);
+            // remaining element is dropped with the iterator
+        });
+        consumer.join().expect("no-panic");
+        assert_eq!(drops.load(Ordering::SeqCst), 2);
+
+        drop(first);
+        assert_eq!(drops.load(Ordering::SeqCst), 3);
+    });
+}
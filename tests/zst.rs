@@ -0,0 +1,300 @@
+use orx_fixed_vec::*;
+use orx_pinned_vec::CapacityState;
+use orx_pseudo_default::PseudoDefault;
+
+/// Zero-sized element other than the unit type.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct Unit;
+
+fn units(capacity: usize, len: usize) -> FixedVec<()> {
+    let mut vec = FixedVec::new(capacity);
+    for _ in 0..len {
+        vec.push(());
+    }
+    vec
+}
+
+#[test]
+fn capacity() {
+    let vec = FixedVec::<()>::new(4);
+    assert_eq!(vec.capacity(), 4);
+    assert_eq!(vec.room(), 4);
+    assert_eq!(vec.capacity_state(), CapacityState::FixedCapacity(4));
+    assert!(!vec.is_full());
+
+    let vec = FixedVec::<()>::new(0);
+    assert_eq!(vec.capacity(), 0);
+    assert!(vec.is_full());
+
+    let vec = FixedVec::<()>::pseudo_default();
+    assert_eq!(vec.capacity(), 0);
+
+    let vec = FixedVec::<()>::new(usize::MAX);
+    assert_eq!(vec.capacity(), usize::MAX);
+}
+
+#[test]
+fn push_until_full() {
+    let mut vec = units(3, 3);
+    assert!(vec.is_full());
+    assert_eq!(vec.len(), 3);
+
+    let error = vec.try_push(()).expect_err("is-full");
+    assert_eq!(error.capacity(), 3);
+    assert_eq!(error.len(), 3);
+
+    assert!(vec.try_insert(0, ()).is_err());
+    assert!(vec.try_extend_from_slice(&[()]).is_err());
+    assert!(vec.try_extend([()]).is_err());
+
+    let mut remaining = vec.extend_bounded([(), ()]);
+    assert_eq!(remaining.next(), Some(()));
+    assert_eq!(vec.len(), 3);
+}
+
+#[test]
+#[should_panic]
+fn push_when_full() {
+    let mut vec = units(3, 3);
+    vec.push(());
+}
+
+#[test]
+#[should_panic]
+fn extend_beyond_capacity() {
+    let mut vec = FixedVec::<()>::new(3);
+    vec.extend(core::iter::repeat_n((), 4));
+}
+
+#[test]
+fn pinned_vec() {
+    let mut vec = units(8, 2);
+
+    let _ = vec.push_get_ptr(());
+    vec.extend_from_slice(&[(), ()]);
+    vec.insert(1, ());
+    assert_eq!(vec.len(), 6);
+
+    assert_eq!(vec.remove(0), ());
+    assert_eq!(vec.pop(), Some(()));
+    vec.swap(0, 1);
+    vec.truncate(2);
+    assert_eq!(vec.len(), 2);
+
+    assert_eq!(vec.get(1), Some(&()));
+    assert_eq!(vec.get(2), None);
+    assert!(vec.get_mut(0).is_some());
+    assert_eq!(vec.first(), Some(&()));
+    assert_eq!(vec.last(), Some(&()));
+    assert_eq!(vec.iter().count(), 2);
+    assert_eq!(vec.iter_mut().count(), 2);
+    assert_eq!(vec.iter_rev().count(), 2);
+    assert!(vec.get_ptr(7).is_some());
+    assert!(vec.get_ptr(8).is_none());
+
+    // elements of zero-sized types do not have distinct addresses
+    assert_eq!(vec.index_of(&()), None);
+
+    vec.clear();
+    assert!(vec.is_empty());
+    assert_eq!(vec.capacity(), 8);
+}
+
+#[test]
+fn fixed_vec_methods() {
+    let mut vec = units(10, 6);
+
+    assert_eq!(vec.drain(1..3).count(), 2);
+    vec.retain(|_| true);
+    vec.dedup_by_key(|_| 0);
+    assert_eq!(vec.len(), 1);
+
+    let mut other = units(4, 4);
+    vec.append(&mut other);
+    assert_eq!(vec.len(), 5);
+    assert_eq!(other.capacity(), 4);
+
+    let split = vec.split_off(3);
+    assert_eq!(split.len(), 2);
+    assert_eq!(split.capacity(), 2);
+    assert_eq!(vec.capacity(), 10);
+
+    assert_eq!(vec.swap_remove(0), ((), Some((2, 0))));
+    let (removed, relocations) = vec.swap_remove_many(&[0, 1]);
+    assert_eq!(removed.len(), 2);
+    assert!(relocations.is_empty());
+    assert!(vec.is_empty());
+
+    let idx = vec.push_get_idx(());
+    assert_eq!(vec.get_by_idx(idx), Some(&()));
+}
+
+#[test]
+fn spare_capacity() {
+    let mut vec = units(5, 2);
+    assert_eq!(vec.spare_capacity_mut().len(), 3);
+
+    vec.write_with(|writer| {
+        while !writer.is_full() {
+            writer.write(());
+        }
+    });
+    assert!(vec.is_full());
+    assert!(vec.spare_capacity_mut().is_empty());
+}
+
+#[test]
+fn conversions() {
+    let vec: FixedVec<()> = vec![(); 3].into();
+    assert_eq!(vec.len(), 3);
+    assert_eq!(vec.capacity(), 3);
+
+    let std_vec: Vec<()> = vec.into();
+    assert_eq!(std_vec.len(), 3);
+
+    let vec = FixedVec::from_vec_exact(vec![(); 5]);
+    assert_eq!(vec.capacity(), 5);
+
+    let vec = FixedVec::collect_exact(core::iter::repeat_n((), 7));
+    assert_eq!(vec.capacity(), 7);
+
+    let vec: FixedVec<()> = core::iter::repeat_n((), 7).collect();
+    assert_eq!(vec.capacity(), 7);
+
+    let vec = FixedVec::from_iter_with_capacity(9, core::iter::repeat_n((), 7)).expect("fits");
+    assert_eq!(vec.capacity(), 9);
+    let vec = FixedVec::from_iter_with_capacity(3, core::iter::repeat_n((), 7));
+    assert!(vec.is_err());
+
+    let vec = FixedVec::from_allocator_vec(allocator_api2::vec![(); 4]);
+    assert_eq!(vec.capacity(), 4);
+    assert_eq!(vec.into_allocator_vec().len(), 4);
+}
+
+#[test]
+fn common_traits() {
+    let mut vec = FixedVec::<Unit>::new(4);
+    vec.extend([Unit]);
+    vec.extend(&[Unit]);
+
+    let clone = vec.clone();
+    assert_eq!(clone.capacity(), 4);
+    assert_eq!(clone.len(), 2);
+    assert_eq!(clone, vec);
+    assert_eq!(vec, [Unit, Unit]);
+
+    assert_eq!(format!("{:?}", vec), "FixedVec { data: [Unit, Unit] }");
+    assert_eq!(vec.as_ref().len(), 2);
+    assert_eq!(vec.as_mut().len(), 2);
+    assert_eq!(vec[1], Unit);
+    assert_eq!(vec[..].len(), 2);
+
+    assert_eq!((&vec).into_iter().count(), 2);
+    assert_eq!((&mut vec).into_iter().count(), 2);
+    assert_eq!(vec.into_iter().count(), 2);
+}
+
+#[test]
+fn into_concurrent() {
+    let vec = units(4, 1);
+    let con_vec = vec.into_concurrent();
+    assert_eq!(con_vec.capacity(), 4);
+    assert_eq!(con_vec.max_capacity(), 4);
+    assert_eq!(con_vec.grow_to(4), Ok(4));
+    assert!(con_vec.grow_to(5).is_err());
+
+    for i in 1..4 {
+        unsafe { con_vec.get_ptr_mut(i).write(()) };
+    }
+    assert_eq!(con_vec.slices(0..4).map(|x| x.len()), Some(4));
+    assert_eq!(con_vec.slices(0..5), None);
+    assert_eq!(unsafe { con_vec.iter(4) }.count(), 4);
+    assert_eq!(unsafe { con_vec.iter_over_range(1..3) }.count(), 2);
+    assert_eq!(unsafe { con_vec.ptr_iter_unchecked(0..4) }.count(), 4);
+
+    let clone = unsafe { con_vec.clone_with_len(2) };
+    assert_eq!(clone.capacity(), 4);
+
+    let vec = unsafe { con_vec.into_inner(4) };
+    assert_eq!(vec.len(), 4);
+    assert_eq!(vec.capacity(), 4);
+    assert!(vec.is_full());
+
+    let vec = unsafe { clone.into_inner(2) };
+    assert_eq!(vec.len(), 2);
+    assert_eq!(vec.capacity(), 4);
+}
+
+#[test]
+fn into_concurrent_filled_with() {
+    let con_vec = units(5, 2).into_concurrent_filled_with(|| ());
+    let vec = unsafe { con_vec.into_inner(5) };
+    assert_eq!(vec.len(), 5);
+    assert_eq!(vec.capacity(), 5);
+}
+
+#[test]
+fn concurrent_reserve() {
+    let mut con_vec = FixedVec::<()>::new(2).into_concurrent();
+    let capacity = unsafe { con_vec.reserve_maximum_concurrent_capacity(0, 10) };
+    assert_eq!(capacity, 10);
+    assert_eq!(con_vec.capacity(), 10);
+
+    let capacity = unsafe { con_vec.reserve_maximum_concurrent_capacity_fill_with(0, 16, || ()) };
+    assert_eq!(capacity, 16);
+
+    let vec = unsafe { con_vec.into_inner(16) };
+    assert_eq!(vec.capacity(), 16);
+    assert!(vec.is_full());
+}
+
+#[test]
+fn concurrent_into_iter_and_clear() {
+    let con_vec = units(6, 6).into_concurrent();
+    let iter = unsafe { con_vec.into_iter(1..4) };
+    assert_eq!(iter.count(), 3);
+
+    let mut con_vec = units(6, 6).into_concurrent();
+    unsafe { con_vec.clear(6) };
+    let vec = unsafe { con_vec.into_inner(0) };
+    assert!(vec.is_empty());
+    assert_eq!(vec.capacity(), 6);
+}
+
+#[test]
+fn concurrent_bag() {
+    let bag = ConcurrentFixedBag::<()>::new(3);
+    assert_eq!(bag.push(()), Ok(0));
+    assert_eq!(bag.push(()), Ok(1));
+    assert_eq!(bag.push(()), Ok(2));
+    assert_eq!(bag.push(()), Err(()));
+
+    let vec = bag.into_inner();
+    assert_eq!(vec.len(), 3);
+    assert_eq!(vec.capacity(), 3);
+}
+
+#[test]
+fn fixed_ring() {
+    let mut ring = FixedRing::from(units(3, 2));
+    assert_eq!(ring.capacity(), 3);
+    assert_eq!(ring.len(), 2);
+    assert!(ring.push_back(()).is_ok());
+    assert_eq!(ring.push_back_overwriting(()), Some(()));
+    assert_eq!(ring.len(), 3);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_round_trip() {
+    let vec = units(5, 2);
+    let json = serde_json::to_string(&vec).expect("serialized");
+    assert_eq!(json, r#"{"capacity":5,"data":[null,null]}"#);
+
+    let vec: FixedVec<()> = serde_json::from_str(&json).expect("deserialized");
+    assert_eq!(vec.len(), 2);
+    assert_eq!(vec.capacity(), 5);
+
+    let result = serde_json::from_str::<FixedVec<()>>(r#"{"capacity":1,"data":[null,null]}"#);
+    assert!(result.is_err());
+}